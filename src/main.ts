@@ -29,11 +29,40 @@ export default class FormattoPlugin extends Plugin {
 
     /** Load and Save Options */
     async loadOptions() {
-        this.settings = Object.assign(
-            {},
-            DEFAULT_OPTIONS,
-            await this.loadData(),
-        );
+        const savedOptions: Partial<FormattoPluginOptions> =
+            (await this.loadData()) ?? {};
+
+        // Merge each option section separately,
+        // so options added in newer versions also get default values.
+        this.settings = {
+            headingGaps: {
+                ...DEFAULT_OPTIONS.headingGaps,
+                ...savedOptions.headingGaps,
+            },
+            otherGaps: {
+                ...DEFAULT_OPTIONS.otherGaps,
+                ...savedOptions.otherGaps,
+            },
+            formatOptions: {
+                ...DEFAULT_OPTIONS.formatOptions,
+                ...savedOptions.formatOptions,
+            },
+            otherOptions: {
+                ...DEFAULT_OPTIONS.otherOptions,
+                ...savedOptions.otherOptions,
+            },
+        };
+
+        // "insertNewline" was replaced by "finalNewline".
+        const savedFormatOptions = savedOptions.formatOptions;
+        if (
+            savedFormatOptions?.insertNewline !== undefined &&
+            savedFormatOptions.finalNewline === undefined
+        ) {
+            this.settings.formatOptions.finalNewline =
+                savedFormatOptions.insertNewline ? "ensureSingle" : "none";
+        }
+        delete this.settings.formatOptions.insertNewline;
     }
     async saveOptions() {
         await this.saveData(this.settings);
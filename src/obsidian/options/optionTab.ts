@@ -6,6 +6,18 @@ import { FALLBACK_OPTIONS } from "./optionTypes.js";
 
 import type { App } from "obsidian";
 import type FormattoPlugin from "@src/main.ts";
+import type {
+    CalloutTypeCase,
+    FinalNewline,
+    HardLineBreakStyle,
+    HeadingCase,
+    HeadingStyle,
+    LineEnding,
+    LinkDefinitionPosition,
+    TableOfContentsLinkStyle,
+    ThematicBreakStyle,
+    TopHeadingLevel,
+} from "./optionTypes.js";
 
 export class FormattoOptionTab extends PluginSettingTab {
     private plugin: FormattoPlugin;
@@ -43,15 +55,22 @@ export class FormattoOptionTab extends PluginSettingTab {
         const debounceMsg = debounce(
             (value: string) => {
                 if (value !== "") {
-                    // Check if the value is a valid number
-                    if (isNaN(parseInt(value)) || parseInt(value) < 0) {
-                        new Notice(this.noticeMessages.invalidNumberMessage);
-                        return;
-                    }
-                    // Check if the value is a whole number
-                    if (this.checkDecimal(value)) {
-                        new Notice(this.noticeMessages.notWholeNumberMessage);
-                        return;
+                    // A range of gaps is written as "min-max".
+                    for (const bound of value.split("-")) {
+                        // Check if the value is a valid number
+                        if (isNaN(parseInt(bound)) || parseInt(bound) < 0) {
+                            new Notice(
+                                this.noticeMessages.invalidNumberMessage,
+                            );
+                            return;
+                        }
+                        // Check if the value is a whole number
+                        if (this.checkDecimal(bound)) {
+                            new Notice(
+                                this.noticeMessages.notWholeNumberMessage,
+                            );
+                            return;
+                        }
                     }
                 }
             },
@@ -65,6 +84,10 @@ export class FormattoOptionTab extends PluginSettingTab {
                     LOCALE_CATEGORY.OPTION_WARNINGS,
                     "Gap value must be a whole number and it needs to be at least 0.",
                 )}
+                ${getLocale(
+                    LOCALE_CATEGORY.OPTION_WARNINGS,
+                    'A range like "1-3" keeps existing gaps within the range.', // eslint-disable-line
+                )}
             </div>`;
             div.className = "setting-item-description";
         });
@@ -353,6 +376,34 @@ export class FormattoOptionTab extends PluginSettingTab {
                         await this.plugin.saveOptions();
                     }),
             );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Between code blocks"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps between consecutive code blocks. Leave empty to use gaps before code blocks.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeCodeBlocks ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.betweenCodeBlocks ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.betweenCodeBlocks =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
         new Setting(containerEl)
             .setName(
                 getLocale(
@@ -411,32 +462,1209 @@ export class FormattoOptionTab extends PluginSettingTab {
                         await this.plugin.saveOptions();
                     }),
             );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Between callouts"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps between consecutive callouts. Leave empty to use gaps before callouts.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeCallouts ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.betweenCallouts ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
 
-        // Format Options
-        containerEl.createEl("h2", {
-            text: getLocale(LOCALE_CATEGORY.OPTION_SECTIONS, "Format options"),
-        });
+                        this.plugin.settings.otherGaps.betweenCallouts = value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
         new Setting(containerEl)
             .setName(
                 getLocale(
-                    LOCALE_CATEGORY.FORMAT_OPTIONS,
-                    "Newline at the end of a document",
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before blockquotes after headings",
                 ),
             )
             .setDesc(
                 getLocale(
-                    LOCALE_CATEGORY.FORMAT_OPTIONS,
-                    "Inserts a newline at the end of a document.",
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    'Decides gaps before "blockquotes that are after headings."', // eslint-disable-line
                 ),
             )
-            .addToggle((text) =>
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps
+                                .beforeBlockquotesAfterHeadings ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps
+                            .beforeBlockquotesAfterHeadings ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeBlockquotesAfterHeadings =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before blockquotes"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    'Decides gaps before "blockquotes"', // eslint-disable-line
+                ),
+            )
+            .addText((text) =>
                 text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeBlockquotes ?? "",
+                        ),
+                    )
                     .setValue(
-                        this.plugin.settings.formatOptions.insertNewline ??
-                            false,
+                        this.plugin.settings.otherGaps.beforeBlockquotes ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeBlockquotes =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before math blocks"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before math blocks.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeMathBlocks ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.beforeMathBlocks ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeMathBlocks = value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.OTHER_GAPS, "After math blocks"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps after math blocks. (Headings keep their own gaps.)",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.afterMathBlocks ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.afterMathBlocks ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.afterMathBlocks = value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before comment blocks"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before comment blocks.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeComments ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.beforeComments ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeComments = value;
+                        await this.plugin.saveOptions();
+                    }),
+            );        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before thematic breaks"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before thematic breaks.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeThematicBreaks ??
+                                "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.beforeThematicBreaks ??
+                            "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeThematicBreaks =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.OTHER_GAPS, "After thematic breaks"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps after thematic breaks. (Headings keep their own gaps.)",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.afterThematicBreaks ??
+                                "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.afterThematicBreaks ??
+                            "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.afterThematicBreaks =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.OTHER_GAPS, "Before footnotes"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before footnotes.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeFootnotes ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.beforeFootnotes ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeFootnotes = value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Before link definitions",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.OTHER_GAPS,
+                    "Decides gaps before link reference definitions.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.otherGaps.beforeLinkDefinitions ??
+                                "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.otherGaps.beforeLinkDefinitions ??
+                            "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.otherGaps.beforeLinkDefinitions =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+
+        // Format Options
+        containerEl.createEl("h2", {
+            text: getLocale(LOCALE_CATEGORY.OPTION_SECTIONS, "Format options"),
+        });
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Newline at the end of a document",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Decides newlines at the end of a document.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "keep",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Same as the document",
+                        ),
+                    )
+                    .addOption(
+                        "ensureSingle",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "One newline",
+                        ),
+                    )
+                    .addOption(
+                        "none",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "No newline"),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions.finalNewline ??
+                            "ensureSingle",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.finalNewline =
+                            value as FinalNewline;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Thematic break style",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Replaces every thematic break with the same style.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "preserve",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Preserve"),
+                    )
+                    .addOption(
+                        "dashes",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Dashes (---)",
+                        ),
+                    )
+                    .addOption(
+                        "asterisks",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Asterisks (***)",
+                        ),
+                    )
+                    .addOption(
+                        "underscores",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Underscores (___)",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions.thematicBreakStyle ??
+                            "preserve",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.thematicBreakStyle =
+                            value as ThematicBreakStyle;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Move footnotes to the end",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Moves footnotes to the end of a document, in the order of their first references.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions.moveFootnotesToEnd ??
+                            false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.moveFootnotesToEnd =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Renumber footnotes"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Renumbers numeric footnotes by the order of their first references.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions.renumberFootnotes ??
+                            false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.renumberFootnotes =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Link definition position",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Decides where link reference definitions are placed.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "preserve",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Preserve"),
+                    )
+                    .addOption(
+                        "documentEnd",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "End of the document",
+                        ),
+                    )
+                    .addOption(
+                        "sectionEnd",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "End of the heading section",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .linkDefinitionPosition ?? "preserve",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.linkDefinitionPosition =
+                            value as LinkDefinitionPosition;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Deduplicate link definitions",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Removes link reference definitions identical to a previous one.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .deduplicateLinkDefinitions ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.deduplicateLinkDefinitions =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Sort link definitions",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Sorts grouped link reference definitions alphabetically by their labels.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .sortLinkDefinitions ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.sortLinkDefinitions =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Callout type case"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Changes the case of callout types.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "preserve",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Preserve"),
+                    )
+                    .addOption(
+                        "lowercase",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Lowercase ([!note])",
+                        ),
+                    )
+                    .addOption(
+                        "uppercase",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Uppercase ([!NOTE])",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions.calloutTypeCase ??
+                            "preserve",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.calloutTypeCase =
+                            value as CalloutTypeCase;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Callout type aliases",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Replaces callout types with their preferred names. (ex: warn=warning, info=note)",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.formatOptions.calloutTypeAliases ??
+                                "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions.calloutTypeAliases ??
+                            "",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.calloutTypeAliases =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Space after callout markers",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Inserts a space after quote markers of callouts and blockquotes.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .ensureSpaceAfterCalloutMarkers ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.ensureSpaceAfterCalloutMarkers =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Format inside callouts and blockquotes",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Formats the contents of callouts and blockquotes with the same options.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .formatQuoteInteriors ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.formatQuoteInteriors =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Max blank lines in contents",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Limits runs of blank lines inside content sections. Leave empty for no limit.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.formatOptions
+                                .maxConsecutiveBlankLinesInContent ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .maxConsecutiveBlankLinesInContent ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.formatOptions.maxConsecutiveBlankLinesInContent =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Remove trailing whitespace",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Removes trailing spaces and tabs outside of code, math, HTML and comment blocks.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .removeTrailingWhitespace ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.removeTrailingWhitespace =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Hard line break style",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Decides how hard line breaks are written when trailing whitespace is removed.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "preserve",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Two spaces"),
+                    )
+                    .addOption(
+                        "backslash",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Backslash (\\)",
+                        ),
+                    )
+                    .addOption(
+                        "html",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "HTML (<br>)",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions.hardLineBreakStyle ??
+                            "preserve",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.hardLineBreakStyle =
+                            value as HardLineBreakStyle;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Line endings"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Decides line endings of a formatted document.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "auto",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Same as the document",
+                        ),
+                    )
+                    .addOption(
+                        "lf",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "LF (\\n)"),
+                    )
+                    .addOption(
+                        "crlf",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "CRLF (\\r\\n)",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions.lineEnding ?? "auto",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.lineEnding =
+                            value as LineEnding;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Normalize hash headings",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Puts one space after the hashes of headings, and removes leading spaces, repeated spaces and closing hashes. Also detects headings without a space after two or more hashes. A single hash without a space is kept, as it is a tag. (ex: `#tag`)",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .normalizeAtxHeadings ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.normalizeAtxHeadings =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Heading style"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Rewrites headings with the same style. Only level 1 and 2 headings can be underlined.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "preserve",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Preserve"),
+                    )
+                    .addOption(
+                        "atx",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Hashes (# Heading)",
+                        ),
+                    )
+                    .addOption(
+                        "setext",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Underlines (===, ---)",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions.headingStyle ??
+                            "preserve",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.headingStyle =
+                            value as HeadingStyle;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Fix skipped heading levels",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Makes each heading at most one level deeper than its parent heading.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .fixSkippedHeadingLevels ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.fixSkippedHeadingLevels =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Single level 1 heading",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Demotes level 1 headings after the first one, with every heading after them.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .demoteExtraH1Headings ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.demoteExtraH1Headings =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Top heading level"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Shifts every heading so the top level heading has this level.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "preserve",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Preserve"),
+                    )
+                    .addOption(
+                        "h1",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Level 1 (#)",
+                        ),
+                    )
+                    .addOption(
+                        "h2",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Level 2 (##)",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions.topHeadingLevel ??
+                            "preserve",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.topHeadingLevel =
+                            value as TopHeadingLevel;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Number headings"),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Prefixes headings with hierarchical numbers like 1., 1.1. and 1.1.1. Existing numbers are replaced.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions.numberHeadings ??
+                            false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.numberHeadings =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Heading numbering start level",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Headings above this level are not numbered, and restart the numbers below them.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.formatOptions
+                                .headingNumberingStartLevel ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .headingNumberingStartLevel ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.formatOptions.headingNumberingStartLevel =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Generate tables of contents",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Replaces everything between <!-- toc --> and <!-- tocstop --> with a list of headings.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .generateTableOfContents ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.generateTableOfContents =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Table of contents link style",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Decides how headings are linked in tables of contents.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "wikilink",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Wikilinks ([[#Heading]])",
+                        ),
+                    )
+                    .addOption(
+                        "markdown",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Markdown links ([Heading](#heading))",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .tableOfContentsLinkStyle ?? "wikilink",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.tableOfContentsLinkStyle =
+                            value as TableOfContentsLinkStyle;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Table of contents depth",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "The number of heading levels in tables of contents. Leave empty for every level.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.formatOptions
+                                .tableOfContentsDepth ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .tableOfContentsDepth ?? "",
+                    )
+                    .onChange(async (value) => {
+                        debounceMsg(value);
+
+                        this.plugin.settings.formatOptions.tableOfContentsDepth =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Remove trailing punctuation in headings",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Removes punctuation characters at the end of heading titles.",
+                ),
+            )
+            .addToggle((text) =>
+                text
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .removeHeadingTrailingPunctuation ?? false,
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.removeHeadingTrailingPunctuation =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Trailing punctuation in headings",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Punctuation characters removed from the end of heading titles.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.formatOptions
+                                .headingTrailingPunctuation ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .headingTrailingPunctuation ?? "",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.headingTrailingPunctuation =
+                            value;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Heading case"))
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Changes the case of heading titles. Inline code, links and wikilinks are left untouched.",
+                ),
+            )
+            .addDropdown((dropdown) =>
+                dropdown
+                    .addOption(
+                        "preserve",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Preserve"),
+                    )
+                    .addOption(
+                        "title",
+                        getLocale(LOCALE_CATEGORY.FORMAT_OPTIONS, "Title Case"),
+                    )
+                    .addOption(
+                        "sentence",
+                        getLocale(
+                            LOCALE_CATEGORY.FORMAT_OPTIONS,
+                            "Sentence case",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions.headingCase ??
+                            "preserve",
+                    )
+                    .onChange(async (value) => {
+                        this.plugin.settings.formatOptions.headingCase =
+                            value as HeadingCase;
+                        await this.plugin.saveOptions();
+                    }),
+            );
+        new Setting(containerEl)
+            .setName(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Small words in title case",
+                ),
+            )
+            .setDesc(
+                getLocale(
+                    LOCALE_CATEGORY.FORMAT_OPTIONS,
+                    "Comma separated words that stay lowercase in title case, unless they are the first or the last word.",
+                ),
+            )
+            .addText((text) =>
+                text
+                    .setPlaceholder(
+                        this.putDefaultIndicator(
+                            FALLBACK_OPTIONS.formatOptions
+                                .headingCaseSmallWords ?? "",
+                        ),
+                    )
+                    .setValue(
+                        this.plugin.settings.formatOptions
+                            .headingCaseSmallWords ?? "",
                     )
                     .onChange(async (value) => {
-                        this.plugin.settings.formatOptions.insertNewline =
+                        this.plugin.settings.formatOptions.headingCaseSmallWords =
                             value;
                         await this.plugin.saveOptions();
                     }),
@@ -24,15 +24,125 @@ export interface OtherGaps {
     beforeCodeBlocks: string;
     /** Decides gaps before \"code blocks that are after headings.\" */
     beforeCodeBlocksAfterHeadings: string;
+    /** Decides gaps between consecutive code blocks. (Falls back to `beforeCodeBlocks`.) */
+    betweenCodeBlocks: string;
     /** Decides gaps before \"callouts that are after headings.\" */
     beforeCalloutsAfterHeadings: string;
     /** Decides gaps before \"callouts that are after content sections.\" */
     beforeCallouts: string;
+    /** Decides gaps between consecutive callouts. (Falls back to `beforeCallouts`.) */
+    betweenCallouts: string;
+    /** Decides gaps before \"blockquotes that are after headings.\" */
+    beforeBlockquotesAfterHeadings: string;
+    /** Decides gaps before \"blockquotes that are after content sections.\" */
+    beforeBlockquotes: string;
+    /** Decides gaps before math blocks. */
+    beforeMathBlocks: string;
+    /** Decides gaps after math blocks. (Headings keep their own gaps.) */
+    afterMathBlocks: string;
+    /** Decides gaps before comment blocks. */
+    beforeComments: string;
+    /** Decides gaps before thematic breaks. */
+    beforeThematicBreaks: string;
+    /** Decides gaps after thematic breaks. (Headings keep their own gaps.) */
+    afterThematicBreaks: string;
+    /** Decides gaps before footnotes. */
+    beforeFootnotes: string;
+    /** Decides gaps before link reference definitions. */
+    beforeLinkDefinitions: string;
 }
 
+export type ThematicBreakStyle =
+    | "preserve"
+    | "dashes"
+    | "asterisks"
+    | "underscores";
+
+export type LinkDefinitionPosition = "preserve" | "documentEnd" | "sectionEnd";
+
+export type CalloutTypeCase = "preserve" | "lowercase" | "uppercase";
+
+export type HardLineBreakStyle = "preserve" | "backslash" | "html";
+
+export type LineEnding = "auto" | "lf" | "crlf";
+
+export type FinalNewline = "keep" | "ensureSingle" | "none";
+
+export type HeadingStyle = "preserve" | "atx" | "setext";
+
+export type TopHeadingLevel = "preserve" | "h1" | "h2";
+
+export type TableOfContentsLinkStyle = "wikilink" | "markdown";
+
+export type HeadingCase = "preserve" | "title" | "sentence";
+
 export interface FormatOptions {
-    /** Inserts a newline at the end of a document. */
-    insertNewline: boolean;
+    /** @deprecated Replaced by `finalNewline`, and only read to migrate saved options. */
+    insertNewline?: boolean;
+    /** Decides newlines at the end of a document. */
+    finalNewline: FinalNewline;
+    /** Replaces every thematic break with the same style. */
+    thematicBreakStyle: ThematicBreakStyle;
+    /** Moves footnotes to the end of a document, in the order of their first references. */
+    moveFootnotesToEnd: boolean;
+    /** Renumbers numeric footnotes by the order of their first references. */
+    renumberFootnotes: boolean;
+    /** Decides where link reference definitions are placed. */
+    linkDefinitionPosition: LinkDefinitionPosition;
+    /** Removes link reference definitions identical to a previous one. */
+    deduplicateLinkDefinitions: boolean;
+    /** Sorts grouped link reference definitions alphabetically by their labels. */
+    sortLinkDefinitions: boolean;
+    /** Changes the case of callout types. */
+    calloutTypeCase: CalloutTypeCase;
+    /** Replaces callout types with their preferred names. (ex: `warn=warning, info=note`) */
+    calloutTypeAliases: string;
+    /** Inserts a space after quote markers of callouts and blockquotes. */
+    ensureSpaceAfterCalloutMarkers: boolean;
+    /** Formats the contents of callouts and blockquotes with the same options. */
+    formatQuoteInteriors: boolean;
+    /**
+     * Limits runs of blank lines inside content sections. (No limit when empty.)
+     * At least one blank line is kept between paragraphs.
+     */
+    maxConsecutiveBlankLinesInContent: string;
+    /** Removes trailing spaces and tabs outside of verbatim sections. */
+    removeTrailingWhitespace: boolean;
+    /** Decides how hard line breaks are written when trailing whitespace is removed. */
+    hardLineBreakStyle: HardLineBreakStyle;
+    /** Decides line endings of a formatted document. */
+    lineEnding: LineEnding;
+    /**
+     * Normalizes hash headings. (ex: `  ##Title  ##` -> `## Title`)
+     * A single hash without a space is kept as a tag. (ex: `#tag`)
+     */
+    normalizeAtxHeadings: boolean;
+    /** Rewrites headings with the same style. */
+    headingStyle: HeadingStyle;
+    /** Makes each heading at most one level deeper than its parent. (ex: `##` -> `####`) */
+    fixSkippedHeadingLevels: boolean;
+    /** Demotes level 1 headings after the first one, with every heading after them. */
+    demoteExtraH1Headings: boolean;
+    /** Shifts every heading so the top level heading has this level. */
+    topHeadingLevel: TopHeadingLevel;
+    /** Prefixes headings with hierarchical numbers. (ex: `1.`, `1.1.`, `1.1.1.`) */
+    numberHeadings: boolean;
+    /** The heading level where numbering starts. */
+    headingNumberingStartLevel: string;
+    /** Generates a list of headings between `<!-- toc -->` and `<!-- tocstop -->`. */
+    generateTableOfContents: boolean;
+    /** Decides how headings are linked in tables of contents. */
+    tableOfContentsLinkStyle: TableOfContentsLinkStyle;
+    /** The number of heading levels in tables of contents. (All levels when empty.) */
+    tableOfContentsDepth: string;
+    /** Removes trailing punctuation characters from heading titles. (ex: `Title:` -> `Title`) */
+    removeHeadingTrailingPunctuation: boolean;
+    /** Punctuation characters removed from the end of heading titles. */
+    headingTrailingPunctuation: string;
+    /** Changes the case of heading titles, except inline code, links and wikilinks. */
+    headingCase: HeadingCase;
+    /** Words that stay lowercase in title case. (ex: `a, an, the`) */
+    headingCaseSmallWords: string;
 }
 
 export interface OtherOptions {
@@ -70,10 +180,46 @@ export const FALLBACK_OTHER_GAPS: Partial<OtherGaps> = {
     beforeCodeBlocksAfterHeadings: "0",
     beforeCalloutsAfterHeadings: "0",
     beforeCallouts: "1",
+    beforeBlockquotesAfterHeadings: "0",
+    beforeBlockquotes: "1",
+    beforeMathBlocks: "1",
+    afterMathBlocks: "1",
+    beforeComments: "1",
+    beforeThematicBreaks: "1",
+    afterThematicBreaks: "1",
+    beforeFootnotes: "1",
+    beforeLinkDefinitions: "1",
 };
 
 export const FALLBACK_FORMAT_OPTIONS: Partial<FormatOptions> = {
-    insertNewline: true,
+    finalNewline: "ensureSingle",
+    thematicBreakStyle: "preserve",
+    moveFootnotesToEnd: false,
+    renumberFootnotes: false,
+    linkDefinitionPosition: "preserve",
+    deduplicateLinkDefinitions: false,
+    sortLinkDefinitions: false,
+    calloutTypeCase: "preserve",
+    calloutTypeAliases: "",
+    ensureSpaceAfterCalloutMarkers: false,
+    formatQuoteInteriors: false,
+    removeTrailingWhitespace: false,
+    hardLineBreakStyle: "preserve",
+    lineEnding: "auto",
+    normalizeAtxHeadings: false,
+    headingStyle: "preserve",
+    fixSkippedHeadingLevels: false,
+    demoteExtraH1Headings: false,
+    topHeadingLevel: "preserve",
+    numberHeadings: false,
+    headingNumberingStartLevel: "1",
+    generateTableOfContents: false,
+    tableOfContentsLinkStyle: "wikilink",
+    removeHeadingTrailingPunctuation: false,
+    headingTrailingPunctuation: ".:;!",
+    headingCase: "preserve",
+    headingCaseSmallWords:
+        "a, an, and, as, at, but, by, for, in, nor, of, on, or, per, the, to, via, vs",
 };
 
 export const FALLBACK_OTHER_OPTIONS: Partial<OtherOptions> = {
@@ -106,8 +252,19 @@ export const EMPTY_OTHER_GAPS: Partial<OtherGaps> = {
     beforeContentsAfterCodeBlocks: "",
     beforeCodeBlocks: "",
     beforeCodeBlocksAfterHeadings: "",
+    betweenCodeBlocks: "",
     beforeCalloutsAfterHeadings: "",
     beforeCallouts: "",
+    betweenCallouts: "",
+    beforeBlockquotesAfterHeadings: "",
+    beforeBlockquotes: "",
+    beforeMathBlocks: "",
+    afterMathBlocks: "",
+    beforeComments: "",
+    beforeThematicBreaks: "",
+    afterThematicBreaks: "",
+    beforeFootnotes: "",
+    beforeLinkDefinitions: "",
 };
 
 export const DEFAULT_OPTIONS: FormattoPluginOptions = {
@@ -79,10 +79,34 @@ pub fn format_document(input: &str, js_options: JsValue, js_locales: JsValue) ->
     }
 }
 
+const BYTE_ORDER_MARK: &str = "\u{feff}";
+
 /// Parses an input and returns a formatted string.
 fn parse_input(input: &str, preferences: &Preferences) -> Result<String, Box<dyn Error>> {
+    // Lines are parsed without carriage returns, and line endings are restored at the end.
+    let line_ending = utils::get_line_ending(input, preferences.options.format_options.line_ending);
+    let input = &input.replace("\r\n", "\n");
+
+    // A byte order mark is kept at the start of a document.
+    let (byte_order_mark, input) = match input.strip_prefix(BYTE_ORDER_MARK) {
+        Some(input) => (BYTE_ORDER_MARK, input),
+        None => ("", input.as_str()),
+    };
+
     let sections = tools::parsing::get_sections(input, preferences)?;
-    let output = tools::formatting::get_formatted_string(sections, preferences)?;
+    let sections = tools::transforming::get_transformed_sections(sections, preferences)?;
+    let original_layout = tools::formatting::get_original_layout(input, &sections);
+    let output = tools::formatting::get_formatted_string_with_original_layout(
+        sections,
+        &original_layout,
+        preferences,
+    )?;
+
+    let output = format!("{}{}", byte_order_mark, output);
 
-    Ok(output)
+    if line_ending == "\n" {
+        Ok(output)
+    } else {
+        Ok(output.replace('\n', line_ending))
+    }
 }
@@ -26,17 +26,208 @@ pub struct OtherGaps {
     pub before_code_blocks: Option<String>,
     /// Decides gaps before \"code blocks that are after headings.\"
     pub before_code_blocks_after_headings: Option<String>,
+    /// Decides gaps between consecutive code blocks. (Falls back to `before_code_blocks`.)
+    pub between_code_blocks: Option<String>,
     /// Decides gaps before \"callouts that are after headings.\"
     pub before_callouts_after_headings: Option<String>,
     /// Decides gaps before \"callouts that are after content sections.\"
     pub before_callouts: Option<String>,
+    /// Decides gaps between consecutive callouts. (Falls back to `before_callouts`.)
+    pub between_callouts: Option<String>,
+    /// Decides gaps before \"blockquotes that are after headings.\"
+    pub before_blockquotes_after_headings: Option<String>,
+    /// Decides gaps before \"blockquotes that are after content sections.\"
+    pub before_blockquotes: Option<String>,
+    /// Decides gaps before math blocks.
+    pub before_math_blocks: Option<String>,
+    /// Decides gaps after math blocks. (Headings keep their own gaps.)
+    pub after_math_blocks: Option<String>,
+    /// Decides gaps before comment blocks.
+    pub before_comments: Option<String>,
+    /// Decides gaps before thematic breaks.
+    pub before_thematic_breaks: Option<String>,
+    /// Decides gaps after thematic breaks. (Headings keep their own gaps.)
+    pub after_thematic_breaks: Option<String>,
+    /// Decides gaps before footnotes.
+    pub before_footnotes: Option<String>,
+    /// Decides gaps before link reference definitions.
+    pub before_link_definitions: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ThematicBreakStyle {
+    Preserve,
+    /// `---`
+    Dashes,
+    /// `***`
+    Asterisks,
+    /// `___`
+    Underscores,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkDefinitionPosition {
+    Preserve,
+    /// Moves them to the end of a document.
+    DocumentEnd,
+    /// Moves them to the end of the heading section that contains them.
+    SectionEnd,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum CalloutTypeCase {
+    Preserve,
+    /// `[!note]`
+    Lowercase,
+    /// `[!NOTE]`
+    Uppercase,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum HardLineBreakStyle {
+    /// Two trailing spaces.
+    Preserve,
+    /// `\`
+    Backslash,
+    /// `<br>`
+    Html,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum LineEnding {
+    /// Follows the line ending used the most in a document.
+    Auto,
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    Crlf,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum FinalNewline {
+    /// Ends with a newline when the original document does.
+    Keep,
+    /// Ends with exactly one newline.
+    EnsureSingle,
+    /// Ends without a newline.
+    None,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum HeadingStyle {
+    /// Keeps the style of each heading.
+    Preserve,
+    /// `# Heading`
+    Atx,
+    /// `Heading` followed by `===` or `---`. Only for level 1 and 2 headings.
+    Setext,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TopHeadingLevel {
+    /// Keeps heading levels.
+    Preserve,
+    /// Shifts headings so the top level heading is `#`.
+    H1,
+    /// Shifts headings so the top level heading is `##`.
+    H2,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TableOfContentsLinkStyle {
+    /// `[[#Heading]]`
+    Wikilink,
+    /// `[Heading](#heading)`
+    Markdown,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum HeadingCase {
+    /// Keeps the case of headings.
+    Preserve,
+    /// `Lorem Ipsum of the Printing Industry`
+    Title,
+    /// `Lorem ipsum of the printing industry`
+    Sentence,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FormatOptions {
     /// Inserts a newline at the end of a document.
+    /// Replaced by `final_newline`, and only used when it is not set.
     pub insert_newline: Option<bool>,
+    /// Decides newlines at the end of a document.
+    pub final_newline: Option<FinalNewline>,
+    /// Replaces every thematic break with the same style.
+    pub thematic_break_style: Option<ThematicBreakStyle>,
+    /// Moves footnotes to the end of a document, in the order of their first references.
+    pub move_footnotes_to_end: Option<bool>,
+    /// Renumbers numeric footnotes by the order of their first references.
+    pub renumber_footnotes: Option<bool>,
+    /// Decides where link reference definitions are placed.
+    pub link_definition_position: Option<LinkDefinitionPosition>,
+    /// Removes link reference definitions identical to a previous one.
+    pub deduplicate_link_definitions: Option<bool>,
+    /// Sorts grouped link reference definitions alphabetically by their labels.
+    pub sort_link_definitions: Option<bool>,
+    /// Changes the case of callout types.
+    pub callout_type_case: Option<CalloutTypeCase>,
+    /// Replaces callout types with their preferred names. (ex: `warn=warning, info=note`)
+    pub callout_type_aliases: Option<String>,
+    /// Inserts a space after quote markers of callouts and blockquotes. (ex: `>text` -> `> text`)
+    pub ensure_space_after_callout_markers: Option<bool>,
+    /// Formats the contents of callouts and blockquotes with the same options.
+    pub format_quote_interiors: Option<bool>,
+    /// Limits runs of blank lines inside content sections. (No limit when empty.)
+    /// At least one blank line is kept between paragraphs.
+    pub max_consecutive_blank_lines_in_content: Option<String>,
+    /// Removes trailing spaces and tabs outside of verbatim sections.
+    pub remove_trailing_whitespace: Option<bool>,
+    /// Decides how hard line breaks are written when trailing whitespace is removed.
+    pub hard_line_break_style: Option<HardLineBreakStyle>,
+    /// Decides line endings of a formatted document.
+    pub line_ending: Option<LineEnding>,
+    /// Normalizes hash headings. (ex: `  ##Title  ##` -> `## Title`)
+    /// A single hash without a space is kept as a tag. (ex: `#tag`)
+    pub normalize_atx_headings: Option<bool>,
+    /// Rewrites headings with the same style.
+    pub heading_style: Option<HeadingStyle>,
+    /// Makes each heading at most one level deeper than its parent. (ex: `##` -> `####`)
+    pub fix_skipped_heading_levels: Option<bool>,
+    /// Demotes level 1 headings after the first one, with every heading after them.
+    pub demote_extra_h1_headings: Option<bool>,
+    /// Shifts every heading so the top level heading has this level.
+    pub top_heading_level: Option<TopHeadingLevel>,
+    /// Prefixes headings with hierarchical numbers. (ex: `1.`, `1.1.`, `1.1.1.`)
+    pub number_headings: Option<bool>,
+    /// The heading level where numbering starts. (1 when empty.)
+    pub heading_numbering_start_level: Option<String>,
+    /// Removes trailing punctuation characters from heading titles. (ex: `Title:` -> `Title`)
+    pub remove_heading_trailing_punctuation: Option<bool>,
+    /// Punctuation characters removed from the end of heading titles. (`.:;!` when empty.)
+    pub heading_trailing_punctuation: Option<String>,
+    /// Changes the case of heading titles, except inline code, links and wikilinks.
+    pub heading_case: Option<HeadingCase>,
+    /// Words that stay lowercase in title case. (ex: `a, an, the`)
+    /// The default words are used when empty.
+    pub heading_case_small_words: Option<String>,
+    /// Generates a list of headings between `<!-- toc -->` and `<!-- tocstop -->`.
+    pub generate_table_of_contents: Option<bool>,
+    /// Decides how headings are linked in tables of contents.
+    pub table_of_contents_link_style: Option<TableOfContentsLinkStyle>,
+    /// The number of heading levels in tables of contents. (All levels when empty.)
+    pub table_of_contents_depth: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
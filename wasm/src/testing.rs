@@ -1,10 +1,15 @@
 use crate::utils::{set_panic_hook, Preferences};
 use serde_json::Value;
 
-use crate::option_schema::{FormatOptions, HeadingGaps, OtherGaps, OtherOptions, PluginOptions};
+use crate::option_schema::{
+    CalloutTypeCase, FormatOptions, HardLineBreakStyle, HeadingCase, HeadingGaps, HeadingStyle,
+    LineEnding, LinkDefinitionPosition, OtherGaps, OtherOptions, PluginOptions,
+    TableOfContentsLinkStyle, ThematicBreakStyle, TopHeadingLevel,
+};
 
 mod formatting;
 mod parsing;
+mod transforming;
 mod utils;
 
 #[allow(dead_code)]
@@ -35,11 +40,51 @@ fn get_example_options() -> PluginOptions {
             before_contents_after_code_blocks: Some("1".to_string()),
             before_code_blocks: Some("1".to_string()),
             before_code_blocks_after_headings: Some("0".to_string()),
+            between_code_blocks: None,
             before_callouts_after_headings: Some("0".to_string()),
             before_callouts: Some("1".to_string()),
+            between_callouts: None,
+            before_blockquotes_after_headings: Some("0".to_string()),
+            before_blockquotes: Some("1".to_string()),
+            before_math_blocks: Some("1".to_string()),
+            after_math_blocks: Some("1".to_string()),
+            before_comments: Some("1".to_string()),
+            before_thematic_breaks: Some("1".to_string()),
+            after_thematic_breaks: Some("1".to_string()),
+            before_footnotes: Some("1".to_string()),
+            before_link_definitions: Some("1".to_string()),
         },
         format_options: FormatOptions {
             insert_newline: Some(false),
+            final_newline: None,
+            thematic_break_style: Some(ThematicBreakStyle::Preserve),
+            move_footnotes_to_end: Some(false),
+            renumber_footnotes: Some(false),
+            link_definition_position: Some(LinkDefinitionPosition::Preserve),
+            deduplicate_link_definitions: Some(false),
+            sort_link_definitions: Some(false),
+            callout_type_case: Some(CalloutTypeCase::Preserve),
+            callout_type_aliases: Some("".to_string()),
+            ensure_space_after_callout_markers: Some(false),
+            format_quote_interiors: Some(false),
+            max_consecutive_blank_lines_in_content: None,
+            remove_trailing_whitespace: Some(false),
+            hard_line_break_style: Some(HardLineBreakStyle::Preserve),
+            line_ending: Some(LineEnding::Auto),
+            normalize_atx_headings: Some(false),
+            heading_style: Some(HeadingStyle::Preserve),
+            fix_skipped_heading_levels: Some(false),
+            demote_extra_h1_headings: Some(false),
+            top_heading_level: Some(TopHeadingLevel::Preserve),
+            number_headings: Some(false),
+            heading_numbering_start_level: Some("1".to_string()),
+            remove_heading_trailing_punctuation: Some(false),
+            heading_trailing_punctuation: Some("".to_string()),
+            heading_case: Some(HeadingCase::Preserve),
+            heading_case_small_words: Some("".to_string()),
+            generate_table_of_contents: Some(false),
+            table_of_contents_link_style: Some(TableOfContentsLinkStyle::Wikilink),
+            table_of_contents_depth: Some("".to_string()),
         },
         other_options: OtherOptions {
             notify_when_unchanged: Some(false),
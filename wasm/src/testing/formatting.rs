@@ -1,8 +1,24 @@
 mod code_blocks;
+mod comment_blocks;
+mod contents;
+mod final_newline;
+mod trailing_whitespace;
+mod gap_ranges;
+mod html_blocks;
+mod indented_code_blocks;
+mod math_blocks;
 mod properties;
+mod thematic_breaks;
 mod callouts;
 
 mod headings {
     mod alternate_headings;
+    mod atx_normalization;
+    mod case;
     mod hash_headings;
+    mod heading_style;
+    mod level_repair;
+    mod numbering;
+    mod table_of_contents;
+    mod trailing_punctuation;
 }
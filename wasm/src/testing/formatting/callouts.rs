@@ -97,3 +97,67 @@ fn case_3() {
 
     assert_eq!(output, expected_output);
 }
+
+/// Blockquotes and callouts have their own gaps.
+#[test]
+fn case_4() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.other_gaps.before_blockquotes = Some("0".to_string());
+    preferences.options.other_gaps.before_callouts = Some("2".to_string());
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+> Blockquote
+
+> [!note]
+> Callout"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+> Blockquote
+
+
+> [!note]
+> Callout"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Consecutive callouts have their own gaps, and fall back to gaps before callouts.
+/// They are kept apart to not be merged.
+#[test]
+fn case_5() {
+    setup();
+
+    let input = r#"> [!note]
+> Callout 1
+
+> [!tip]
+> Callout 2"#;
+
+    let mut preferences = get_example_preferences();
+    preferences.options.other_gaps.before_callouts = Some("2".to_string());
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"> [!note]
+> Callout 1
+
+
+> [!tip]
+> Callout 2"#;
+
+    assert_eq!(output, expected_output);
+
+    preferences.options.other_gaps.between_callouts = Some("0".to_string());
+
+    let sections = get_sections(expected_output, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+
+    assert_eq!(output, input);
+}
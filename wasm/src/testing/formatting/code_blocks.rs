@@ -61,3 +61,36 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
 
     assert_eq!(output, expected_output);
 }
+
+/// Consecutive code blocks have their own gaps.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.other_gaps.before_code_blocks = Some("2".to_string());
+    preferences.options.other_gaps.between_code_blocks = Some("0".to_string());
+
+    let input = r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+```ts
+console.log("Hello, World!");
+```
+
+```rust
+println!("Hello, World!");
+```"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+
+```ts
+console.log("Hello, World!");
+```
+```rust
+println!("Hello, World!");
+```"#;
+
+    assert_eq!(output, expected_output);
+}
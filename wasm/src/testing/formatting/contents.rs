@@ -0,0 +1,87 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+/// Runs of blank lines inside content sections are limited.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.max_consecutive_blank_lines_in_content =
+        Some("1".to_string());
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+
+
+- Lorem Ipsum is simply dummy text.
+  
+- Lorem Ipsum is simply dummy text.
+```ts
+console.log("Hello, World!");
+
+
+
+```"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+- Lorem Ipsum is simply dummy text.
+  
+- Lorem Ipsum is simply dummy text.
+
+```ts
+console.log("Hello, World!");
+
+
+
+```"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Blank lines are kept without a limit.
+#[test]
+fn case_2() {
+    setup();
+
+    let input = r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let sections = get_sections(input, &get_example_preferences()).unwrap();
+    let output = get_formatted_string(sections, &get_example_preferences()).unwrap();
+
+    assert_eq!(output, input);
+}
+
+/// Paragraphs are not merged when the limit is 0.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.max_consecutive_blank_lines_in_content =
+        Some("0".to_string());
+
+    let input = r#"Lorem Ipsum is simply dummy text.
+
+
+Lorem Ipsum is simply dummy text."#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"Lorem Ipsum is simply dummy text.
+
+Lorem Ipsum is simply dummy text."#;
+
+    assert_eq!(output, expected_output);
+}
@@ -0,0 +1,55 @@
+use crate::{
+    option_schema::FinalNewline,
+    parse_input,
+    testing::{get_example_preferences, setup},
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+
+    for (final_newline, input, expected_output) in [
+        (FinalNewline::EnsureSingle, "Lorem Ipsum", "Lorem Ipsum\n"),
+        (
+            FinalNewline::EnsureSingle,
+            "Lorem Ipsum\n\n\n",
+            "Lorem Ipsum\n",
+        ),
+        (FinalNewline::None, "Lorem Ipsum\n", "Lorem Ipsum"),
+        (FinalNewline::Keep, "Lorem Ipsum\n\n", "Lorem Ipsum\n"),
+        (FinalNewline::Keep, "Lorem Ipsum", "Lorem Ipsum"),
+    ]
+    .iter()
+    {
+        preferences.options.format_options.final_newline = Some(*final_newline);
+
+        let output = parse_input(input, &preferences).unwrap();
+        assert_eq!(output, *expected_output);
+
+        // Formatting again does not change the output.
+        assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+    }
+}
+
+/// The replaced "insert_newline" option is used when the new one is not set.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.insert_newline = Some(true);
+
+    assert_eq!(
+        parse_input("Lorem Ipsum", &preferences).unwrap(),
+        "Lorem Ipsum\n"
+    );
+
+    preferences.options.format_options.final_newline = Some(FinalNewline::None);
+
+    assert_eq!(
+        parse_input("Lorem Ipsum", &preferences).unwrap(),
+        "Lorem Ipsum"
+    );
+}
@@ -0,0 +1,105 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{
+        formatting::{
+            get_formatted_string_with_original_layout, get_original_layout, OriginalLayout,
+        },
+        parsing::get_sections,
+    },
+};
+
+/// Original gaps are kept within ranges, and clamped outside of them.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences
+        .options
+        .other_gaps
+        .before_contents_after_code_blocks = Some("1-2".to_string());
+    preferences.options.other_gaps.before_code_blocks = Some("0-1".to_string());
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+```ts
+console.log("Hello, World!");
+```
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+
+
+
+```rust
+println!("Hello, World!");
+```
+
+
+
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let original_layout = get_original_layout(input, &sections);
+    let output =
+        get_formatted_string_with_original_layout(sections, &original_layout, &preferences)
+            .unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+```ts
+console.log("Hello, World!");
+```
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+```rust
+println!("Hello, World!");
+```
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Sections that are not found in the original document get the minimum gaps.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences
+        .options
+        .other_gaps
+        .before_contents_after_code_blocks = Some("2-4".to_string());
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+```ts
+console.log("Hello, World!");
+```
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string_with_original_layout(
+        sections,
+        &OriginalLayout::default(),
+        &preferences,
+    )
+    .unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+```ts
+console.log("Hello, World!");
+```
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    assert_eq!(output, expected_output);
+}
@@ -0,0 +1,64 @@
+use crate::{
+    parse_input,
+    testing::{get_example_preferences, setup},
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.normalize_atx_headings = Some(true);
+
+    let input = r#"  #Not a heading
+##Heading  2 ##
+Lorem Ipsum
+   ###   Heading 3
+```
+##Code
+```"#;
+
+    let expected_output = r#"  #Not a heading
+
+
+
+## Heading 2
+Lorem Ipsum
+
+### Heading 3
+```
+##Code
+```"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Headings are not changed when the option is disabled.
+#[test]
+fn case_2() {
+    setup();
+
+    let preferences = get_example_preferences();
+
+    let input = r#"## Heading  2 ##"#;
+
+    assert_eq!(parse_input(input, &preferences).unwrap(), input);
+}
+
+/// Tags are not headings, so they are kept.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.normalize_atx_headings = Some(true);
+
+    let input = r#"#tag
+Lorem Ipsum #tag"#;
+
+    assert_eq!(parse_input(input, &preferences).unwrap(), input);
+}
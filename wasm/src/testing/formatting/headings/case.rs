@@ -0,0 +1,105 @@
+use crate::{
+    option_schema::HeadingCase,
+    parse_input,
+    testing::{get_example_preferences, setup},
+};
+
+/// Title case keeps small words lowercase, except the first and the last word.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.heading_case = Some(HeadingCase::Title);
+
+    let input = r#"## the lord Of the rings
+## using `the code` in [the link](https://example.com) and [[the wikilink]]
+## what is it for
+## an API for iPhone"#;
+
+    let expected_output = r#"## The Lord of the Rings
+
+
+
+## Using `the code` in [the link](https://example.com) and [[the wikilink]]
+
+
+
+## What Is It For
+
+
+
+## An API for iPhone"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Sentence case keeps acronyms and words with inner capitals.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.heading_case = Some(HeadingCase::Sentence);
+
+    let input = r#"## 1. Using The API With JavaScript
+Lorem Ipsum
+
+Why I Like `Rust Code`
+---"#;
+
+    let expected_output = r#"## 1. Using the API with JavaScript
+Lorem Ipsum
+
+
+
+Why I like `Rust Code`
+---"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Small words can be replaced.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.heading_case = Some(HeadingCase::Title);
+    format_options.heading_case_small_words = Some("with, THE".to_string());
+
+    let input = r#"## a day with the lorem ipsum"#;
+    let expected_output = r#"## A Day with the Lorem Ipsum"#;
+
+    assert_eq!(parse_input(input, &preferences).unwrap(), expected_output);
+}
+
+/// Tags are kept in both cases.
+#[test]
+fn case_4() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.heading_case = Some(HeadingCase::Title);
+
+    let input = r#"## lorem #tag ipsum"#;
+    let expected_output = r#"## Lorem #tag Ipsum"#;
+
+    assert_eq!(parse_input(input, &preferences).unwrap(), expected_output);
+
+    preferences.options.format_options.heading_case = Some(HeadingCase::Sentence);
+
+    let input = r#"## Lorem #Tag Ipsum"#;
+    let expected_output = r#"## Lorem #Tag ipsum"#;
+
+    assert_eq!(parse_input(input, &preferences).unwrap(), expected_output);
+}
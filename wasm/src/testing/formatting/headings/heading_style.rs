@@ -0,0 +1,97 @@
+use crate::{
+    option_schema::HeadingStyle,
+    parse_input,
+    testing::{get_example_preferences, setup},
+};
+
+/// Alternate headings become hash headings.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.heading_style = Some(HeadingStyle::Atx);
+
+    let input = r#"Heading 1
+===
+Lorem Ipsum
+
+Heading 2
+---
+### Heading 3 ###"#;
+
+    let expected_output = r#"# Heading 1
+Lorem Ipsum
+
+## Heading 2
+
+### Heading 3 ###"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Level 1 and 2 hash headings become alternate headings.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.heading_style = Some(HeadingStyle::Setext);
+
+    let input = r#"# Heading 1
+Lorem Ipsum
+## Heading 2
+### Heading 3
+## > Quote
+## AB"#;
+
+    let expected_output = r#"Heading 1
+=========
+Lorem Ipsum
+
+Heading 2
+---------
+
+### Heading 3
+
+
+## > Quote
+
+
+AB
+---"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// An alternate heading after a content section keeps an empty line before it.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.heading_style = Some(HeadingStyle::Setext);
+    preferences.options.heading_gaps.before_top_level_headings = Some("0".to_string());
+
+    let input = r#"Lorem Ipsum
+# Heading 1"#;
+
+    let expected_output = r#"Lorem Ipsum
+
+Heading 1
+========="#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
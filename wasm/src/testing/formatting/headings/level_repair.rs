@@ -0,0 +1,102 @@
+use crate::{
+    option_schema::TopHeadingLevel,
+    parse_input,
+    testing::{get_example_preferences, setup},
+};
+
+/// Skipped levels are filled.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.fix_skipped_heading_levels = Some(true);
+
+    let input = r#"## Heading 2
+#### Heading 4
+##### Heading 5
+#### Heading 4
+## Heading 2
+###### Heading 6"#;
+
+    let expected_output = r#"## Heading 2
+
+### Heading 4
+
+#### Heading 5
+
+
+### Heading 4
+
+
+
+## Heading 2
+
+### Heading 6"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Level 1 headings after the first one are demoted with their sub headings.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.demote_extra_h1_headings = Some(true);
+
+    let input = r#"# Heading 1
+## Heading 2
+Heading 1
+===
+## Heading 2"#;
+
+    let expected_output = r#"# Heading 1
+
+## Heading 2
+
+
+Heading 1
+---------
+
+### Heading 2"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Every heading is shifted so the top level heading has the chosen level.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.top_heading_level = Some(TopHeadingLevel::H1);
+
+    let input = r#"### Heading 3
+Lorem Ipsum
+#### Heading 4
+### Heading 3"#;
+
+    let expected_output = r#"# Heading 3
+Lorem Ipsum
+
+## Heading 4
+
+
+
+# Heading 3"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
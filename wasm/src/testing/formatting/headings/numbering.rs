@@ -0,0 +1,186 @@
+use crate::{
+    parse_input,
+    testing::{get_example_preferences, setup},
+};
+
+/// Headings are numbered, and old numbers are replaced.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.number_headings = Some(true);
+
+    let input = r#"# 3. Heading
+## Heading
+### 1.1.1. Heading
+## 2024 Review
+# Heading
+Heading
+---"#;
+
+    let expected_output = r#"# 1. Heading
+
+## 1.1. Heading
+
+### 1.1.1. Heading
+
+
+## 1.2. 2024 Review
+
+
+
+# 2. Heading
+
+2.1. Heading
+---"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Headings above the start level are not numbered, and restart the numbers.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.number_headings = Some(true);
+    format_options.heading_numbering_start_level = Some("2".to_string());
+
+    let input = r#"# Heading
+## Heading
+## Heading
+# Heading
+## Heading"#;
+
+    let expected_output = r#"# Heading
+
+## 1. Heading
+
+
+## 2. Heading
+
+
+
+# Heading
+
+## 1. Heading"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Skipped levels don't add numbers.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.number_headings = Some(true);
+
+    let input = r#"# Heading
+### Heading
+## Heading"#;
+
+    let expected_output = r#"# 1. Heading
+
+### 1.1. Heading
+
+
+## 1.2. Heading"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Numbers start from the top heading of a document, even if it is not a level 1 heading.
+#[test]
+fn case_4() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.number_headings = Some(true);
+
+    let input = r#"## Intro
+### Heading
+## Heading"#;
+
+    let expected_output = r#"## 1. Intro
+
+### 1.1. Heading
+
+
+
+## 2. Heading"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Headings without a numbered parent heading are numbered from the first depth.
+#[test]
+fn case_5() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.number_headings = Some(true);
+
+    let input = r#"### Heading
+### Heading
+#### Heading"#;
+
+    let expected_output = r#"### 1. Heading
+
+
+
+### 2. Heading
+
+#### 2.1. Heading"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// A heading before the first level 1 heading is not padded with zeros.
+#[test]
+fn case_6() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.number_headings = Some(true);
+
+    let input = r#"## Heading
+# Heading
+## Heading"#;
+
+    let expected_output = r#"## 1. Heading
+
+
+
+# 2. Heading
+
+## 2.1. Heading"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
@@ -0,0 +1,155 @@
+use crate::{
+    option_schema::TableOfContentsLinkStyle,
+    parse_input,
+    testing::{get_example_preferences, setup},
+};
+
+/// A table of contents is generated with wikilinks.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.generate_table_of_contents = Some(true);
+
+    let input = r#"<!-- toc -->
+- Outdated
+<!-- tocstop -->
+## Heading: 2
+#### Heading 4
+## Heading 2"#;
+
+    let expected_output = r#"<!-- toc -->
+
+- [[#Heading 2]]
+	- [[#Heading 4]]
+- [[#Heading 2]]
+
+<!-- tocstop -->
+
+
+
+## Heading: 2
+
+#### Heading 4
+
+
+
+## Heading 2"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Markdown links have unique anchors, and deeper headings are skipped.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.generate_table_of_contents = Some(true);
+    format_options.table_of_contents_link_style = Some(TableOfContentsLinkStyle::Markdown);
+    format_options.table_of_contents_depth = Some("1".to_string());
+
+    let input = r#"<!-- toc -->
+<!-- tocstop -->
+
+# Lorem Ipsum!
+## Heading 2
+# Lorem Ipsum!"#;
+
+    let expected_output = r#"<!-- toc -->
+
+- [Lorem Ipsum!](#lorem-ipsum)
+- [Lorem Ipsum!](#lorem-ipsum-1)
+
+<!-- tocstop -->
+
+
+
+# Lorem Ipsum!
+
+## Heading 2
+
+
+
+# Lorem Ipsum!"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Links, wikilinks and emphasis in headings are written as their visible text.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.generate_table_of_contents = Some(true);
+    format_options.table_of_contents_link_style = Some(TableOfContentsLinkStyle::Markdown);
+
+    let input = r#"<!-- toc -->
+<!-- tocstop -->
+
+## B [link](http://x)
+## **Lorem** [[Note|Ipsum]] `a_b`"#;
+
+    let expected_output = r#"<!-- toc -->
+
+- [B link](#b-link)
+- [Lorem Ipsum `a_b`](#lorem-ipsum-a_b)
+
+<!-- tocstop -->
+
+
+
+## B [link](http://x)
+
+
+
+## **Lorem** [[Note|Ipsum]] `a_b`"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// Wikilinks are written with the visible text of headings too.
+#[test]
+fn case_4() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.generate_table_of_contents = Some(true);
+
+    let input = r#"<!-- toc -->
+<!-- tocstop -->
+
+## B [link](http://x)"#;
+
+    let expected_output = r#"<!-- toc -->
+
+- [[#B link]]
+
+<!-- tocstop -->
+
+
+
+## B [link](http://x)"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
@@ -0,0 +1,90 @@
+use crate::{
+    parse_input,
+    testing::{get_example_preferences, setup},
+};
+
+/// Trailing punctuation is removed from hash headings and alternate headings.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.remove_heading_trailing_punctuation = Some(true);
+
+    let input = r#"# Heading:
+## Heading!!! ##
+## What?
+## Tom &amp;
+## ...
+Heading.
+---"#;
+
+    let expected_output = r#"# Heading
+
+## Heading
+
+
+## What?
+
+
+## Tom &amp;
+
+
+## ...
+
+
+Heading
+---"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
+
+/// The punctuation characters can be replaced.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.remove_heading_trailing_punctuation = Some(true);
+    format_options.heading_trailing_punctuation = Some("?".to_string());
+
+    let input = r#"## What?
+## Heading:"#;
+
+    let expected_output = r#"## What
+
+
+
+## Heading:"#;
+
+    assert_eq!(parse_input(input, &preferences).unwrap(), expected_output);
+}
+
+/// Heading numbers are kept, so numbering again does not change headings.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.remove_heading_trailing_punctuation = Some(true);
+    format_options.number_headings = Some(true);
+
+    let input = r#"#
+## Heading."#;
+
+    let expected_output = r#"# 1.
+
+## 1.1. Heading"#;
+
+    let output = parse_input(input, &preferences).unwrap();
+    assert_eq!(output, expected_output);
+
+    // Formatting again does not change the output.
+    assert_eq!(parse_input(&output, &preferences).unwrap(), output);
+}
@@ -0,0 +1,36 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+
+
+<details>
+<summary>Summary</summary>
+
+
+
+- List item
+</details>
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let sections = get_sections(input, &get_example_preferences()).unwrap();
+    let output = get_formatted_string(sections, &get_example_preferences()).unwrap();
+    let expected_output = r#"## Heading 2
+<details>
+<summary>Summary</summary>
+
+
+
+- List item
+</details>
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    assert_eq!(output, expected_output);
+}
@@ -0,0 +1,52 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+
+    fn main() {}
+
+    // Comment
+
+
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+    fn main() {}"#;
+
+    let sections = get_sections(input, &get_example_preferences()).unwrap();
+    let output = get_formatted_string(sections, &get_example_preferences()).unwrap();
+    let expected_output = r#"## Heading 2
+    fn main() {}
+
+    // Comment
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+    fn main() {}"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// An indented code block after a content section keeps an empty line before it.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.other_gaps.before_code_blocks = Some("0".to_string());
+
+    let input = r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+    fn main() {}"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+
+    assert_eq!(output, input);
+}
@@ -0,0 +1,42 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+$$
+x^2
+
+y^2
+$$
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+
+
+$$x^2$$
+### Heading 3"#;
+
+    let sections = get_sections(input, &get_example_preferences()).unwrap();
+    let output = get_formatted_string(sections, &get_example_preferences()).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+$$
+x^2
+
+y^2
+$$
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+$$x^2$$
+
+### Heading 3"#;
+
+    assert_eq!(output, expected_output);
+}
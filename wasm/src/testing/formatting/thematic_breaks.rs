@@ -0,0 +1,68 @@
+use crate::{
+    option_schema::ThematicBreakStyle,
+    testing::{get_example_preferences, setup},
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+***
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+
+
+- - -
+### Heading 3"#;
+
+    let sections = get_sections(input, &get_example_preferences()).unwrap();
+    let output = get_formatted_string(sections, &get_example_preferences()).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+***
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+- - -
+
+### Heading 3"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Thematic breaks with the same style.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.other_gaps.before_thematic_breaks = Some("0".to_string());
+    preferences.options.format_options.thematic_break_style = Some(ThematicBreakStyle::Dashes);
+
+    let input = r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+***
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+```
+code
+```
+_ _ _"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+---
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+```
+code
+```
+---"#;
+
+    assert_eq!(output, expected_output);
+}
@@ -0,0 +1,172 @@
+use crate::{
+    option_schema::HardLineBreakStyle,
+    testing::{get_example_preferences, setup},
+    tools::{formatting::get_formatted_string, parsing::get_sections},
+};
+
+/// Trailing whitespace is removed outside of code blocks, and hard line breaks are kept.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences
+        .options
+        .format_options
+        .remove_trailing_whitespace = Some(true);
+
+    let input = "## Heading 2 \t
+Lorem Ipsum is simply dummy text.   
+Lorem Ipsum is simply dummy text.\t
+Lorem Ipsum is simply dummy text.  
+
+> Lorem Ipsum is simply dummy text.  
+> Lorem Ipsum is simply dummy text.  
+```ts  
+console.log(\"Hello, World!\");  
+```";
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = "## Heading 2
+Lorem Ipsum is simply dummy text.  
+Lorem Ipsum is simply dummy text.
+Lorem Ipsum is simply dummy text.
+
+> Lorem Ipsum is simply dummy text.  
+> Lorem Ipsum is simply dummy text.
+
+```ts  
+console.log(\"Hello, World!\");  
+```";
+
+    assert_eq!(output, expected_output);
+}
+
+/// Hard line breaks are converted.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences
+        .options
+        .format_options
+        .remove_trailing_whitespace = Some(true);
+
+    let input = "Lorem Ipsum is simply dummy text.  
+Lorem Ipsum is simply dummy text.  
+
+Lorem Ipsum is simply dummy text.  
+Lorem Ipsum is simply dummy text.";
+
+    for (style, expected_output) in [
+        (
+            HardLineBreakStyle::Backslash,
+            "Lorem Ipsum is simply dummy text.\\
+Lorem Ipsum is simply dummy text.
+
+Lorem Ipsum is simply dummy text.\\
+Lorem Ipsum is simply dummy text.",
+        ),
+        (
+            HardLineBreakStyle::Html,
+            "Lorem Ipsum is simply dummy text.<br>
+Lorem Ipsum is simply dummy text.
+
+Lorem Ipsum is simply dummy text.<br>
+Lorem Ipsum is simply dummy text.",
+        ),
+    ]
+    .iter()
+    {
+        preferences.options.format_options.hard_line_break_style = Some(*style);
+
+        let sections = get_sections(input, &preferences).unwrap();
+        let output = get_formatted_string(sections, &preferences).unwrap();
+
+        assert_eq!(output, *expected_output);
+    }
+}
+
+/// Lines before list items, table rows, callout headers and other quote depths
+/// are not hard line breaks.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.remove_trailing_whitespace = Some(true);
+    format_options.hard_line_break_style = Some(HardLineBreakStyle::Backslash);
+
+    let input = "- Lorem Ipsum  
+- Lorem Ipsum  
+  Lorem Ipsum
+1. Lorem Ipsum  
+2) Lorem Ipsum
+
+| Lorem | Ipsum |  
+| ----- | ----- |  
+| Lorem | Ipsum |
+
+> [!note] Lorem Ipsum  
+> Lorem Ipsum  
+> > Lorem Ipsum";
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = "- Lorem Ipsum
+- Lorem Ipsum\\
+  Lorem Ipsum
+1. Lorem Ipsum
+2) Lorem Ipsum
+
+| Lorem | Ipsum |
+| ----- | ----- |
+| Lorem | Ipsum |
+
+> [!note] Lorem Ipsum
+> Lorem Ipsum
+> > Lorem Ipsum";
+
+    assert_eq!(output, expected_output);
+}
+
+/// Titles of alternate headings don't end with hard line breaks.
+#[test]
+fn case_4() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences
+        .options
+        .format_options
+        .remove_trailing_whitespace = Some(true);
+
+    let input = "Heading 1  
+=========
+
+> Heading 2  
+> ---------";
+
+    let expected_output = "Heading 1
+=========
+> Heading 2
+> ---------";
+
+    for style in [
+        HardLineBreakStyle::Preserve,
+        HardLineBreakStyle::Backslash,
+        HardLineBreakStyle::Html,
+    ]
+    .iter()
+    {
+        preferences.options.format_options.hard_line_break_style = Some(*style);
+
+        let sections = get_sections(input, &preferences).unwrap();
+        let output = get_formatted_string(sections, &preferences).unwrap();
+
+        assert_eq!(output, expected_output);
+    }
+}
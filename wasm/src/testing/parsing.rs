@@ -1,9 +1,16 @@
 mod get_sections {
     mod callouts;
     mod code_blocks;
+    mod comment_blocks;
     mod contents;
+    mod footnotes;
     mod general;
+    mod html_blocks;
+    mod indented_code_blocks;
+    mod link_definitions;
+    mod math_blocks;
     mod properties;
+    mod thematic_breaks;
 
     mod headings {
         mod alternate_headings;
@@ -14,4 +21,9 @@ mod get_sections {
 mod headings {
     mod alternate_headings;
     mod get_top_heading_level;
+    mod normalize_hash_heading;
+}
+
+mod callouts {
+    mod get_callout_header;
 }
@@ -0,0 +1,56 @@
+use crate::{
+    testing::setup,
+    tools::{
+        parsing::callouts::get_callout_header,
+        tokens::{CalloutFold, CalloutHeader},
+    },
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = "> [!NOTE]- Lorem Ipsum";
+
+    let expected_output = CalloutHeader {
+        prefix: "> ".to_string(),
+        callout_type: "NOTE".to_string(),
+        fold: Some(CalloutFold::Collapsed),
+        title: "Lorem Ipsum".to_string(),
+    };
+
+    assert_eq!(get_callout_header(input), Some(expected_output));
+}
+
+#[test]
+fn case_2() {
+    setup();
+
+    let input = ">>[!warn]+";
+
+    let expected_output = CalloutHeader {
+        prefix: ">>".to_string(),
+        callout_type: "warn".to_string(),
+        fold: Some(CalloutFold::Expanded),
+        title: String::new(),
+    };
+
+    assert_eq!(get_callout_header(input), Some(expected_output));
+}
+
+/// Plain blockquotes and invalid callout types.
+#[test]
+fn invalid_input_1() {
+    setup();
+
+    for input in [
+        "> Lorem Ipsum [!note]",
+        "> [!]",
+        "> [!with space] Lorem Ipsum",
+        "[!note] Lorem Ipsum",
+    ]
+    .iter()
+    {
+        assert_eq!(get_callout_header(input), None);
+    }
+}
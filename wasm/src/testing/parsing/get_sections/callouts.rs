@@ -24,8 +24,8 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
             "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
                 .to_string(),
         ),
-        MarkdownSection::Callout("> Callout 1".to_string()),
-        MarkdownSection::Callout("> Callout 2".to_string()),
+        MarkdownSection::Blockquote("> Callout 1".to_string()),
+        MarkdownSection::Blockquote("> Callout 2".to_string()),
     ];
 
     assert_eq!(
@@ -49,9 +49,9 @@ fn case_2() {
 > Callout 3"#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1".to_string()),
-        MarkdownSection::Callout("> Callout 2\n> Callout 2".to_string()),
-        MarkdownSection::Callout("> Callout 3\n> Callout 3\n> Callout 3".to_string()),
+        MarkdownSection::Blockquote("> Callout 1".to_string()),
+        MarkdownSection::Blockquote("> Callout 2\n> Callout 2".to_string()),
+        MarkdownSection::Blockquote("> Callout 3\n> Callout 3\n> Callout 3".to_string()),
     ];
 
     assert_eq!(
@@ -74,8 +74,8 @@ console.log("Hello, World!");
 ```"#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1".to_string()),
-        MarkdownSection::Callout("> Callout 2\n> Callout 2".to_string()),
+        MarkdownSection::Blockquote("> Callout 1".to_string()),
+        MarkdownSection::Blockquote("> Callout 2\n> Callout 2".to_string()),
         MarkdownSection::Code("```ts\nconsole.log(\"Hello, World!\");\n```".to_string()),
     ];
 
@@ -107,10 +107,10 @@ fn case_4() {
 "#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1".to_string()),
+        MarkdownSection::Blockquote("> Callout 1".to_string()),
         MarkdownSection::Heading(HeadingLevel::Top("# Heading 1".to_string())),
-        MarkdownSection::Callout("> Callout 2\n> Callout 2".to_string()),
-        MarkdownSection::Callout("> Callout 3\n> Callout 3\n> Callout 3".to_string()),
+        MarkdownSection::Blockquote("> Callout 2\n> Callout 2".to_string()),
+        MarkdownSection::Blockquote("> Callout 3\n> Callout 3\n> Callout 3".to_string()),
     ];
 
     assert_eq!(
@@ -143,11 +143,11 @@ console.log("Hello, World!");
 "#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1".to_string()),
+        MarkdownSection::Blockquote("> Callout 1".to_string()),
         MarkdownSection::Heading(HeadingLevel::Top("# Heading 1".to_string())),
-        MarkdownSection::Callout("> Callout 2\n> Callout 2".to_string()),
+        MarkdownSection::Blockquote("> Callout 2\n> Callout 2".to_string()),
         MarkdownSection::Code("```ts\nconsole.log(\"Hello, World!\");\n```".to_string()),
-        MarkdownSection::Callout("> Callout 3\n> Callout 3\n> Callout 3".to_string()),
+        MarkdownSection::Blockquote("> Callout 3\n> Callout 3\n> Callout 3".to_string()),
     ];
 
     assert_eq!(
@@ -181,11 +181,11 @@ console.log("Hello, World!");
 "#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1".to_string()),
+        MarkdownSection::Blockquote("> Callout 1".to_string()),
         MarkdownSection::Heading(HeadingLevel::Top("# Heading 1".to_string())),
-        MarkdownSection::Callout("> Callout 2\n> Callout 2".to_string()),
+        MarkdownSection::Blockquote("> Callout 2\n> Callout 2".to_string()),
         MarkdownSection::Code("```ts\nconsole.log(\"Hello, World!\");\n```".to_string()),
-        MarkdownSection::Callout("> Callout 3\n> Callout 3\n> Callout 3".to_string()),
+        MarkdownSection::Blockquote("> Callout 3\n> Callout 3\n> Callout 3".to_string()),
     ];
 
     assert_eq!(
@@ -204,7 +204,7 @@ fn case_7() {
 Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
+        MarkdownSection::Blockquote("> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
     ];
 
     assert_eq!(
@@ -227,7 +227,7 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\n> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
+        MarkdownSection::Blockquote("> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\n> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
     ];
 
     assert_eq!(
@@ -252,7 +252,7 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 ```"#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\n> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
+        MarkdownSection::Blockquote("> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\n> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
         MarkdownSection::Code("```ts\n```".to_string())
     ];
 
@@ -279,7 +279,7 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 ```"#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\n> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
+        MarkdownSection::Blockquote("> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\n> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
         MarkdownSection::Code("```ts\n```".to_string())
     ];
 
@@ -306,7 +306,7 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 ```"#;
 
     let expected_output = vec![
-        MarkdownSection::Callout("> Callout 1\n> Callout 1\n>\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\n> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
+        MarkdownSection::Blockquote("> Callout 1\n> Callout 1\n>\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\nLorem Ipsum is simply dummy text of the printing and typesetting industry.\n> Callout 1\n> Callout 1\n> Callout 1\nLorem Ipsum is simply dummy text of the printing and typesetting industry.".to_string()),
         MarkdownSection::Code("```ts\n```".to_string())
     ];
 
@@ -315,3 +315,25 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
         expected_output
     );
 }
+
+/// Quotes starting with a callout header are callouts.
+#[test]
+fn case_12() {
+    setup();
+
+    let input = r#"> [!note] Callout 1
+> Callout 1
+
+> Blockquote 1
+> [!note] Blockquote 1"#;
+
+    let expected_output = vec![
+        MarkdownSection::Callout("> [!note] Callout 1\n> Callout 1".to_string()),
+        MarkdownSection::Blockquote("> Blockquote 1\n> [!note] Blockquote 1".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
@@ -0,0 +1,65 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+%%
+# TODO
+
+> Not a callout
+%%
+%% Single line comment %%"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Comment("%%\n# TODO\n\n> Not a callout\n%%".to_string()),
+        MarkdownSection::Comment("%% Single line comment %%".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Inline comments at the start of a paragraph don't open comment blocks.
+#[test]
+fn case_2() {
+    setup();
+
+    let input = r#"%%hidden%% visible text
+
+more"#;
+
+    let expected_output = vec![MarkdownSection::Content(
+        "%%hidden%% visible text\n\nmore".to_string(),
+    )];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn invalid_input_1() {
+    setup();
+
+    let input = r#"%%
+# TODO"#;
+    let sections = get_sections(input, &get_example_preferences());
+    assert!(sections.is_err());
+}
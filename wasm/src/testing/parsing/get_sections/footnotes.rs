@@ -0,0 +1,59 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text[^1] of the printing and typesetting industry[^note].
+[^1]: First footnote.
+[^note]: Second footnote.
+
+    Indented paragraph in the footnote.
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text[^1] of the printing and typesetting industry[^note]."
+                .to_string(),
+        ),
+        MarkdownSection::Footnote("[^1]: First footnote.".to_string()),
+        MarkdownSection::Footnote(
+            "[^note]: Second footnote.\n\n    Indented paragraph in the footnote.".to_string(),
+        ),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Invalid footnote definition syntax.
+#[test]
+fn invalid_input_1() {
+    setup();
+
+    let input = r#"[^]: Empty label.
+[^with space]: Label with a space.
+[^1] Missing colon."#;
+
+    let expected_output = vec![MarkdownSection::Content(input.to_string())];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
@@ -1,6 +1,7 @@
 use crate::{
+    parse_input,
     testing::{get_example_preferences, setup},
-    tools::parsing::get_sections,
+    tools::{parsing::get_sections, tokens::MarkdownSection},
 };
 
 #[test]
@@ -15,3 +16,35 @@ fn empty_input() {
         expected_output
     );
 }
+
+/// Leading blank lines are skipped, but the indentation of the first line is kept.
+#[test]
+fn leading_whitespace() {
+    setup();
+
+    let input = "\n  \n    Indented code block.\nLorem Ipsum is simply dummy text.\n\n";
+    let expected_output = vec![
+        MarkdownSection::IndentedCode("    Indented code block.".to_string()),
+        MarkdownSection::Content("Lorem Ipsum is simply dummy text.".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// A byte order mark is kept at the start of a document.
+#[test]
+fn byte_order_mark() {
+    setup();
+
+    let input = "\u{feff}---\nalias: Lorem Ipsum\n---\nLorem Ipsum is simply dummy text.";
+    let expected_output =
+        "\u{feff}---\nalias: Lorem Ipsum\n---\n\n\nLorem Ipsum is simply dummy text.";
+
+    assert_eq!(
+        parse_input(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
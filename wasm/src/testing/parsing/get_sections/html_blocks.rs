@@ -0,0 +1,103 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+/// Markdown inside an HTML block.
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+<details>
+<summary>Summary</summary>
+
+# Not a heading
+
+
+> Not a callout
+</details>
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Html(
+            r#"<details>
+<summary>Summary</summary>
+
+# Not a heading
+
+
+> Not a callout
+</details>"#
+                .to_string(),
+        ),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Nested tags and HTML comments.
+#[test]
+fn case_2() {
+    setup();
+
+    let input = r#"<div class="outer">
+<div>
+
+</div>
+
+</div>
+
+<!--
+### Comment
+-->
+<!-- toc -->"#;
+
+    let expected_output = vec![
+        MarkdownSection::Html("<div class=\"outer\">\n<div>\n\n</div>\n\n</div>".to_string()),
+        MarkdownSection::Html("<!--\n### Comment\n-->".to_string()),
+        MarkdownSection::Html("<!-- toc -->".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// HTML blocks without closing tags end before an empty line.
+#[test]
+fn case_3() {
+    setup();
+
+    let input = r#"<hr>
+<p>
+
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+<span>Inline HTML</span>"#;
+
+    let expected_output = vec![
+        MarkdownSection::Html("<hr>\n<p>".to_string()),
+        MarkdownSection::Content(
+            r#"Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+<span>Inline HTML</span>"#
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
@@ -0,0 +1,85 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+/// Empty lines between indented code chunks.
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+
+    fn main() {}
+
+    # not a heading
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::IndentedCode("    fn main() {}\n\n    # not a heading".to_string()),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Tab indentation right after a heading.
+#[test]
+fn case_2() {
+    setup();
+
+    let input = "## Heading 2\n\tlet a = 1;\n\n\n\tlet b = 2;\n\n\n";
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::IndentedCode("\tlet a = 1;\n\n\n\tlet b = 2;".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Indented lines that can't start an indented code block.
+#[test]
+fn case_3() {
+    setup();
+
+    let input = r#"Lorem Ipsum is simply dummy text
+    of the printing and typesetting industry.
+
+- List item
+
+    Paragraph in the list item."#;
+
+    let expected_output = vec![MarkdownSection::Content(
+        r#"Lorem Ipsum is simply dummy text
+    of the printing and typesetting industry.
+
+- List item
+
+    Paragraph in the list item."#
+            .to_string(),
+    )];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
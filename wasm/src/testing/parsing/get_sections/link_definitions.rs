@@ -0,0 +1,76 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply [dummy text][1] of the [printing][Typesetting].
+
+[1]: https://example.com
+[typesetting]: <https://example.com/a b> "Title"
+   [with title]: https://example.com 'Title'
+Lorem Ipsum is simply dummy text of the printing and typesetting industry."#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply [dummy text][1] of the [printing][Typesetting].".to_string(),
+        ),
+        MarkdownSection::LinkDefinition("[1]: https://example.com".to_string()),
+        MarkdownSection::LinkDefinition(
+            "[typesetting]: <https://example.com/a b> \"Title\"".to_string(),
+        ),
+        MarkdownSection::LinkDefinition("   [with title]: https://example.com 'Title'".to_string()),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Link reference definitions cannot interrupt a paragraph.
+#[test]
+fn case_2() {
+    setup();
+
+    let input = r#"Lorem Ipsum is simply dummy text.
+[1]: https://example.com"#;
+
+    let expected_output = vec![MarkdownSection::Content(input.to_string())];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Invalid link reference definition syntax.
+#[test]
+fn invalid_input_1() {
+    setup();
+
+    let input = r#"[]: https://example.com
+[missing destination]:
+[extra text]: https://example.com "Title" text
+[unclosed title]: https://example.com "Title
+    [indented]: https://example.com"#;
+
+    let expected_output = vec![MarkdownSection::Content(input.to_string())];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
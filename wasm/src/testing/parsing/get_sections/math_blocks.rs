@@ -0,0 +1,99 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+$$
+\begin{aligned}
+# x &= 1 \\
+
+y &= 2
+\end{aligned}
+$$
+---"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::Math(
+            r#"$$
+\begin{aligned}
+# x &= 1 \\
+
+y &= 2
+\end{aligned}
+$$"#
+            .to_string(),
+        ),
+        MarkdownSection::ThematicBreak("---".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Single line math blocks.
+#[test]
+fn case_2() {
+    setup();
+
+    let input = r#"$$x^2$$
+$$\begin{aligned}
+> x
+\end{aligned}$$"#;
+
+    let expected_output = vec![
+        MarkdownSection::Math("$$x^2$$".to_string()),
+        MarkdownSection::Math("$$\\begin{aligned}\n> x\n\\end{aligned}$$".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Lines that only start with the delimiter are a part of a paragraph.
+#[test]
+fn case_3() {
+    setup();
+
+    let input = r#"$$x$$ is the formula
+
+$$ cost is a lot
+
+text"#;
+
+    let expected_output = vec![MarkdownSection::Content(
+        "$$x$$ is the formula\n\n$$ cost is a lot\n\ntext".to_string(),
+    )];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+#[test]
+fn invalid_input_1() {
+    setup();
+
+    let input = r#"$$
+x^2"#;
+    let sections = get_sections(input, &get_example_preferences());
+    assert!(sections.is_err());
+}
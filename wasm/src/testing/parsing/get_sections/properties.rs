@@ -118,7 +118,7 @@ Lorem Ipsum is simply dummy text of the printing and typesetting industry.
 }
 
 /// Invalid property syntax.
-/// It gets read as a content section.
+/// It gets read as a thematic break.
 #[test]
 fn invalid_input_2() {
     setup();
@@ -130,7 +130,8 @@ fn invalid_input_2() {
 ## Heading 2"#;
 
     let expected_output = vec![
-        MarkdownSection::Content("Text\n\n---".to_string()),
+        MarkdownSection::Content("Text".to_string()),
+        MarkdownSection::ThematicBreak("---".to_string()),
         MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
     ];
 
@@ -0,0 +1,67 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{
+        parsing::get_sections,
+        tokens::{HeadingLevel, MarkdownSection},
+    },
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+***
+Lorem Ipsum is simply dummy text of the printing and typesetting industry.
+- - -
+___
+
+---"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("## Heading 2".to_string())),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::ThematicBreak("***".to_string()),
+        MarkdownSection::Content(
+            "Lorem Ipsum is simply dummy text of the printing and typesetting industry."
+                .to_string(),
+        ),
+        MarkdownSection::ThematicBreak("- - -".to_string()),
+        MarkdownSection::ThematicBreak("___".to_string()),
+        MarkdownSection::ThematicBreak("---".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
+
+/// Dash-only lines right after a paragraph are alternate headings.
+#[test]
+fn case_2() {
+    setup();
+
+    let input = r#"Heading 2
+---
+***
+---
+**
+* * * text"#;
+
+    let expected_output = vec![
+        MarkdownSection::Heading(HeadingLevel::Top("Heading 2\n---".to_string())),
+        MarkdownSection::ThematicBreak("***".to_string()),
+        MarkdownSection::ThematicBreak("---".to_string()),
+        MarkdownSection::Content("**\n* * * text".to_string()),
+    ];
+
+    assert_eq!(
+        get_sections(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
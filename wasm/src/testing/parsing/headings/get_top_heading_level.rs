@@ -81,6 +81,65 @@ fn case_4() {
     assert_eq!(get_top_heading_level(&input).unwrap(), expected_output);
 }
 
+/// Lines inside math blocks are not headings.
+#[test]
+fn case_5() {
+    setup();
+
+    let input: Vec<&str> = r#"$$
+# x^2
+$$
+
+## Heading 2
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(get_top_heading_level(&input).unwrap(), expected_output);
+}
+
+/// Lines inside HTML blocks are not headings.
+#[test]
+fn case_6() {
+    setup();
+
+    let input: Vec<&str> = r#"<details>
+
+# Heading 1
+
+</details>
+
+## Heading 2
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(get_top_heading_level(&input).unwrap(), expected_output);
+}
+
+/// Lines inside comment blocks are not headings.
+#[test]
+fn case_7() {
+    setup();
+
+    let input: Vec<&str> = r#"%%
+# TODO
+%%
+
+## Heading 2
+"#
+    .split('\n')
+    .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(get_top_heading_level(&input).unwrap(), expected_output);
+}
+
 #[test]
 fn invalid_input_1() {
     setup();
@@ -103,6 +162,40 @@ INVALID
     assert_eq!(get_top_heading_level(&input).unwrap(), expected_output);
 }
 
+/// Paragraphs starting with `$$` don't open math blocks.
+#[test]
+fn case_8() {
+    setup();
+
+    let input: Vec<&str> = r#"$$x$$ is the formula
+
+$$ cost is a lot
+
+## Heading 2"#
+        .split('\n')
+        .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(get_top_heading_level(&input).unwrap(), expected_output);
+}
+
+/// Paragraphs starting with an inline comment don't open comment blocks.
+#[test]
+fn case_9() {
+    setup();
+
+    let input: Vec<&str> = r#"%%hidden%% visible text
+
+## Heading 2"#
+        .split('\n')
+        .collect();
+
+    let expected_output = 2;
+
+    assert_eq!(get_top_heading_level(&input).unwrap(), expected_output);
+}
+
 #[test]
 fn invalid_input_2() {
     setup();
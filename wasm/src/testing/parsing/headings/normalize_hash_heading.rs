@@ -0,0 +1,38 @@
+use crate::{
+    testing::setup, tools::parsing::headings::hash_headings::normalization::normalize_hash_heading,
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    for (input, expected_output) in [
+        ("## Heading", Some("## Heading")),
+        ("##Heading", Some("## Heading")),
+        ("   ##   Heading", Some("## Heading")),
+        ("## Lorem   Ipsum ##", Some("## Lorem Ipsum")),
+        ("## C#", Some("## C#")),
+        ("## `a   b`  c", Some("## `a   b` c")),
+        ("### ###", Some("###")),
+        ("#", Some("#")),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            normalize_hash_heading(input).as_deref(),
+            *expected_output,
+            "{}",
+            input
+        );
+    }
+}
+
+/// Tags, indented code and lines with more than 6 hashes are not headings.
+#[test]
+fn case_2() {
+    setup();
+
+    for input in ["#tag", "    ## Heading", "####### Heading", "Heading"].iter() {
+        assert_eq!(normalize_hash_heading(input), None, "{}", input);
+    }
+}
@@ -0,0 +1,3 @@
+mod callouts;
+mod footnotes;
+mod link_definitions;
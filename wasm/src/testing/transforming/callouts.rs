@@ -0,0 +1,135 @@
+use crate::{
+    option_schema::CalloutTypeCase,
+    testing::{get_example_preferences, setup},
+    tools::{
+        formatting::get_formatted_string, parsing::get_sections,
+        transforming::get_transformed_sections,
+    },
+};
+
+/// Callout types are replaced with their aliases and lowercased.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.callout_type_case = Some(CalloutTypeCase::Lowercase);
+    format_options.callout_type_aliases = Some("warn = warning, info=note".to_string());
+
+    let input = r#"> [!WARN]- Lorem Ipsum
+> Lorem Ipsum is simply dummy text.
+
+> [!Note]
+> Lorem Ipsum is simply dummy text.
+
+> Lorem Ipsum [!WARN]"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"> [!warning]- Lorem Ipsum
+> Lorem Ipsum is simply dummy text.
+
+> [!note]
+> Lorem Ipsum is simply dummy text.
+
+> Lorem Ipsum [!WARN]"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Spaces are inserted after quote markers.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.callout_type_case = Some(CalloutTypeCase::Uppercase);
+    format_options.ensure_space_after_callout_markers = Some(true);
+
+    let input = r#">[!tip]+ Lorem Ipsum
+>Lorem Ipsum is simply dummy text.
+>
+>>Nested quote.
+lazy continuation line"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"> [!TIP]+ Lorem Ipsum
+> Lorem Ipsum is simply dummy text.
+>
+>> Nested quote.
+lazy continuation line"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Contents of callouts and blockquotes are formatted like a document.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.format_quote_interiors = Some(true);
+
+    let input = r#"> [!note]- Lorem Ipsum
+> ## Heading 2
+> Lorem Ipsum is simply dummy text.
+>```ts
+>console.log("Hello, World!");
+>```
+>
+>
+> > ### Heading 3
+> > Lorem Ipsum is simply dummy text.
+
+> Lorem Ipsum is simply dummy text.
+>
+>
+>
+> Lorem Ipsum is simply dummy text."#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"> [!note]- Lorem Ipsum
+> ## Heading 2
+> Lorem Ipsum is simply dummy text.
+>
+> ```ts
+> console.log("Hello, World!");
+> ```
+>
+> > ### Heading 3
+> > Lorem Ipsum is simply dummy text.
+
+> Lorem Ipsum is simply dummy text.
+>
+>
+>
+> Lorem Ipsum is simply dummy text."#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Interiors that cannot be parsed are kept as they are.
+#[test]
+fn case_4() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.format_quote_interiors = Some(true);
+
+    let input = r#"> Lorem Ipsum is simply dummy text.
+> ```ts
+> console.log("Hello, World!");"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+
+    assert_eq!(output, input);
+}
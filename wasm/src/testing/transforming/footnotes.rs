@@ -0,0 +1,118 @@
+use crate::{
+    testing::{get_example_preferences, setup},
+    tools::{
+        formatting::get_formatted_string, parsing::get_sections,
+        transforming::get_transformed_sections,
+    },
+};
+
+/// Footnotes stay where they are.
+#[test]
+fn case_1() {
+    setup();
+
+    let input = r#"## Heading 2
+Lorem Ipsum[^1] is simply dummy text.
+[^1]: First footnote.
+
+
+[^2]: Second footnote.
+Lorem Ipsum[^2] is simply dummy text."#;
+
+    let preferences = get_example_preferences();
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum[^1] is simply dummy text.
+
+[^1]: First footnote.
+[^2]: Second footnote.
+
+Lorem Ipsum[^2] is simply dummy text."#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Footnotes are moved to the end in the order of their first references.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.move_footnotes_to_end = Some(true);
+    preferences.options.other_gaps.before_footnotes = Some("2".to_string());
+
+    let input = r#"## Heading 2
+[^unused]: Unused footnote.
+Lorem Ipsum[^b] is simply dummy text `[^a]`.
+[^a]: Footnote A.
+    Second line of footnote A.
+
+Lorem Ipsum[^a] is simply dummy text.
+[^b]: Footnote B.
+
+### Heading 3
+```
+[^c]: Not a footnote.
+```"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum[^b] is simply dummy text `[^a]`.
+
+Lorem Ipsum[^a] is simply dummy text.
+
+### Heading 3
+```
+[^c]: Not a footnote.
+```
+
+
+[^b]: Footnote B.
+[^a]: Footnote A.
+    Second line of footnote A.
+[^unused]: Unused footnote."#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Numeric footnotes are renumbered by the order of their first references.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.renumber_footnotes = Some(true);
+
+    let input = r#"## Heading 2
+Lorem Ipsum[^3] is simply[^name] dummy text[^1] `[^1]`.
+Lorem Ipsum[^3] is simply dummy text.
+```
+[^3]
+```
+[^1]: Footnote 1.
+[^2]: Footnote 2.[^3]
+[^3]: Footnote 3.
+[^name]: Named footnote."#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum[^1] is simply[^name] dummy text[^2] `[^1]`.
+Lorem Ipsum[^1] is simply dummy text.
+
+```
+[^3]
+```
+
+[^2]: Footnote 1.
+[^3]: Footnote 2.[^1]
+[^1]: Footnote 3.
+[^name]: Named footnote."#;
+
+    assert_eq!(output, expected_output);
+}
@@ -0,0 +1,137 @@
+use crate::{
+    option_schema::LinkDefinitionPosition,
+    testing::{get_example_preferences, setup},
+    tools::{
+        formatting::get_formatted_string, parsing::get_sections,
+        transforming::get_transformed_sections,
+    },
+};
+
+/// Link reference definitions stay where they are.
+#[test]
+fn case_1() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.other_gaps.before_link_definitions = Some("2".to_string());
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply [dummy text][b].
+
+[b]: https://example.com/b
+[a]: https://example.com/a
+Lorem Ipsum is simply [dummy text][a]."#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply [dummy text][b].
+
+
+[b]: https://example.com/b
+[a]: https://example.com/a
+
+Lorem Ipsum is simply [dummy text][a]."#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Link reference definitions are moved to the end, deduplicated and sorted.
+#[test]
+fn case_2() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    let format_options = &mut preferences.options.format_options;
+    format_options.link_definition_position = Some(LinkDefinitionPosition::DocumentEnd);
+    format_options.deduplicate_link_definitions = Some(true);
+    format_options.sort_link_definitions = Some(true);
+
+    let input = r#"## Heading 2
+Lorem Ipsum is simply [dummy text][b].
+
+[b]: https://example.com/b
+[Alpha]: https://example.com/a
+## Heading 2
+Lorem Ipsum is simply [dummy text][alpha].
+
+[alpha]:   https://example.com/a
+[c]: https://example.com/c"#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply [dummy text][b].
+
+
+
+## Heading 2
+Lorem Ipsum is simply [dummy text][alpha].
+
+[Alpha]: https://example.com/a
+[b]: https://example.com/b
+[c]: https://example.com/c"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Link reference definitions are moved to the end of their heading sections.
+#[test]
+fn case_3() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.format_options.link_definition_position =
+        Some(LinkDefinitionPosition::SectionEnd);
+
+    let input = r#"## Heading 2
+[b]: https://example.com/b
+
+Lorem Ipsum is simply [dummy text][b].
+### Heading 3
+[a]: https://example.com/a
+
+Lorem Ipsum is simply [dummy text][a]."#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply [dummy text][b].
+
+[b]: https://example.com/b
+
+### Heading 3
+Lorem Ipsum is simply [dummy text][a].
+
+[a]: https://example.com/a"#;
+
+    assert_eq!(output, expected_output);
+}
+
+/// Link reference definitions after a content section keep an empty line before them.
+#[test]
+fn case_4() {
+    setup();
+
+    let mut preferences = get_example_preferences();
+    preferences.options.other_gaps.before_link_definitions = Some("0".to_string());
+    preferences.options.format_options.link_definition_position =
+        Some(LinkDefinitionPosition::DocumentEnd);
+
+    let input = r#"[a]: https://example.com/a
+## Heading 2
+Lorem Ipsum is simply [dummy text][a]."#;
+
+    let sections = get_sections(input, &preferences).unwrap();
+    let sections = get_transformed_sections(sections, &preferences).unwrap();
+    let output = get_formatted_string(sections, &preferences).unwrap();
+    let expected_output = r#"## Heading 2
+Lorem Ipsum is simply [dummy text][a].
+
+[a]: https://example.com/a"#;
+
+    assert_eq!(output, expected_output);
+}
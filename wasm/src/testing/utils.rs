@@ -1 +1,2 @@
+mod get_line_ending;
 mod get_locale_string;
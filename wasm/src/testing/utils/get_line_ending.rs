@@ -0,0 +1,36 @@
+use crate::{
+    option_schema::LineEnding,
+    parse_input,
+    testing::{get_example_preferences, setup},
+    utils::get_line_ending,
+};
+
+#[test]
+fn case_1() {
+    setup();
+
+    let input = "---\r\nalias: Lorem Ipsum\r\n---\r\n## Heading 2\nLorem Ipsum";
+
+    assert_eq!(get_line_ending(input, Some(LineEnding::Auto)), "\r\n");
+    assert_eq!(get_line_ending(input, Some(LineEnding::Lf)), "\n");
+    assert_eq!(get_line_ending("Lorem\nIpsum", None), "\n");
+    assert_eq!(
+        get_line_ending("Lorem\nIpsum", Some(LineEnding::Crlf)),
+        "\r\n"
+    );
+}
+
+/// Documents with CRLF line endings are parsed and written back with them.
+#[test]
+fn case_2() {
+    setup();
+
+    let input = "---\r\nalias: Lorem Ipsum\r\n---\r\n## Heading 2\r\n```ts\r\nconsole.log(\"Hello, World!\");\r\n```\r\nLorem Ipsum is simply dummy text.";
+
+    let expected_output = "---\r\nalias: Lorem Ipsum\r\n---\r\n\r\n\r\n## Heading 2\r\n```ts\r\nconsole.log(\"Hello, World!\");\r\n```\r\n\r\nLorem Ipsum is simply dummy text.";
+
+    assert_eq!(
+        parse_input(input, &get_example_preferences()).unwrap(),
+        expected_output
+    );
+}
@@ -1,3 +1,4 @@
 pub mod formatting;
 pub mod parsing;
 pub mod tokens;
+pub mod transforming;
@@ -1,170 +1,359 @@
 use serde_json::Value;
 use std::error::Error;
 
+use crate::option_schema::{FinalNewline, ThematicBreakStyle};
 use crate::tools::tokens::{HeadingLevel, MarkdownSection};
 use crate::{console_error, Preferences};
 
+mod trailing_whitespace;
+
+/// Layout of an original document that formatting can keep.
+#[derive(Debug, Default)]
+pub struct OriginalLayout {
+    /// Numbers of empty lines before each section.
+    pub gaps: Vec<Option<usize>>,
+    /// Whether the document ends with a newline.
+    pub ends_with_newline: bool,
+}
+
 /// Formats a parsed document.
 pub fn get_formatted_string(
     sections: Vec<MarkdownSection>,
     preferences: &Preferences,
 ) -> Result<String, Box<dyn Error>> {
+    get_formatted_string_with_original_layout(sections, &OriginalLayout::default(), preferences)
+}
+
+/// Formats a parsed document.
+/// Gaps with a range of counts keep the original number of empty lines within the range.
+pub fn get_formatted_string_with_original_layout(
+    sections: Vec<MarkdownSection>,
+    original_layout: &OriginalLayout,
+    preferences: &Preferences,
+) -> Result<String, Box<dyn Error>> {
+    use self::trailing_whitespace::remove_trailing_whitespace;
+
     let mut output = String::new();
+    let format_options = &preferences.options.format_options;
 
     // Check which type of section was last parsed.
-    let mut is_right_after_properties = false;
-    let mut is_right_after_heading = false;
-    let mut is_right_after_code_block = false;
+    let mut previous_section: Option<&MarkdownSection> = None;
+
+    // At least one blank line is kept, so that paragraphs are not merged.
+    let max_blank_lines_in_content = match &format_options.max_consecutive_blank_lines_in_content {
+        Some(input) if !input.trim().is_empty() => Some(
+            parse_string_to_usize(&Some(input.trim().to_string()), &preferences.locales)?.max(1),
+        ),
+        _ => None,
+    };
+
+    for (index, section) in sections.iter().enumerate() {
+        let content = match section {
+            MarkdownSection::ThematicBreak(content) => {
+                get_thematic_break(content, format_options.thematic_break_style)
+            }
+            MarkdownSection::Content(content) => match max_blank_lines_in_content {
+                Some(max_count) => limit_blank_lines(content, max_count),
+                None => content.to_string(),
+            },
+            _ => get_section_content(section).to_string(),
+        };
+
+        // Verbatim sections keep their whitespace.
+        let is_verbatim = matches!(
+            section,
+            MarkdownSection::Property(_)
+                | MarkdownSection::Code(_)
+                | MarkdownSection::IndentedCode(_)
+                | MarkdownSection::Math(_)
+                | MarkdownSection::Html(_)
+                | MarkdownSection::Comment(_)
+        );
+        let content = if format_options.remove_trailing_whitespace == Some(true) && !is_verbatim {
+            remove_trailing_whitespace(&content, format_options.hard_line_break_style)
+        } else {
+            content
+        };
+
+        let mut gap = if output.is_empty() {
+            0
+        } else {
+            let original_gap = original_layout.gaps.get(index).copied().flatten();
+
+            get_gap_before_section(section, previous_section, original_gap, preferences)? + 1
+        };
+
+        // A thematic break with dashes right after a content section
+        // would be read as an alternate heading syntax.
+        if gap == 1
+            && matches!(section, MarkdownSection::ThematicBreak(_))
+            && matches!(previous_section, Some(MarkdownSection::Content(_)))
+            && content.trim().chars().all(|c| c == '-')
+        {
+            gap = 2;
+        }
+        // Indented code blocks and link reference definitions can't interrupt a paragraph,
+        // so they would be read as a part of the content section.
+        if gap == 1
+            && matches!(
+                section,
+                MarkdownSection::IndentedCode(_) | MarkdownSection::LinkDefinition(_)
+            )
+            && matches!(previous_section, Some(MarkdownSection::Content(_)))
+        {
+            gap = 2;
+        }
+        // The title of an alternate heading right after a content section
+        // would be read as the last line of the paragraph.
+        if gap == 1
+            && matches!(
+                section,
+                MarkdownSection::Heading(
+                    HeadingLevel::Top(text) | HeadingLevel::FirstSub(text) | HeadingLevel::Sub(text),
+                ) if text.contains('\n')
+            )
+            && matches!(previous_section, Some(MarkdownSection::Content(_)))
+        {
+            gap = 2;
+        }
+        // Quotes without a gap between them would be merged into one.
+        if gap == 1
+            && matches!(
+                section,
+                MarkdownSection::Callout(_) | MarkdownSection::Blockquote(_)
+            )
+            && matches!(
+                previous_section,
+                Some(MarkdownSection::Callout(_) | MarkdownSection::Blockquote(_))
+            )
+        {
+            gap = 2;
+        }
+
+        output.push_str(&insert_line_breaks(&content, gap, 0));
 
+        previous_section = Some(section);
+    }
+
+    // The replaced "insert_newline" option is used when the new one is not set.
+    let final_newline =
+        format_options
+            .final_newline
+            .unwrap_or(match format_options.insert_newline {
+                Some(true) => FinalNewline::EnsureSingle,
+                _ => FinalNewline::None,
+            });
+    let output_length = output.trim_end_matches('\n').len();
+    output.truncate(output_length);
+    match final_newline {
+        FinalNewline::EnsureSingle => output.push('\n'),
+        FinalNewline::Keep if original_layout.ends_with_newline => output.push('\n'),
+        FinalNewline::Keep | FinalNewline::None => {}
+    }
+
+    Ok(output)
+}
+
+/// Returns the number of empty lines that should be inserted before a section.
+fn get_gap_before_section(
+    section: &MarkdownSection,
+    previous_section: Option<&MarkdownSection>,
+    original_gap: Option<usize>,
+    preferences: &Preferences,
+) -> Result<usize, Box<dyn Error>> {
     let options = &preferences.options;
     let locale = &preferences.locales;
 
-    for section in sections {
-        match section {
-            MarkdownSection::Property(content) => {
-                output.push_str(&content);
+    let gap_option = match (section, previous_section) {
+        (MarkdownSection::Property(_), _) => return Ok(0),
+        // Footnotes are grouped together.
+        (MarkdownSection::Footnote(_), Some(MarkdownSection::Footnote(_))) => return Ok(0),
+        // So are link reference definitions.
+        (MarkdownSection::LinkDefinition(_), Some(MarkdownSection::LinkDefinition(_))) => {
+            return Ok(0)
+        }
 
-                is_right_after_properties = true;
-                is_right_after_heading = false;
-                is_right_after_code_block = false;
-            }
-            MarkdownSection::Heading(heading_level) => {
-                match heading_level {
-                    HeadingLevel::Top(content) => {
-                        output.push_str(&insert_line_breaks(
-                            &content,
-                            if output.is_empty() {
-                                0
-                            } else if is_right_after_properties {
-                                parse_string_to_usize(&options.other_gaps.after_properties, locale)?
-                                    + 1
-                            } else {
-                                parse_string_to_usize(
-                                    &options.heading_gaps.before_top_level_headings,
-                                    locale,
-                                )? + 1
-                            },
-                            0,
-                        ));
-                    }
-                    HeadingLevel::FirstSub(content) => {
-                        let formatted = insert_line_breaks(
-                            &content,
-                            if output.is_empty() {
-                                0
-                            } else if is_right_after_properties {
-                                parse_string_to_usize(&options.other_gaps.after_properties, locale)?
-                                    + 1
-                            } else {
-                                parse_string_to_usize(
-                                    &options.heading_gaps.before_first_sub_heading,
-                                    locale,
-                                )? + 1
-                            },
-                            0,
-                        );
-                        output.push_str(&formatted);
-                    }
-                    HeadingLevel::Sub(content) => {
-                        output.push_str(&insert_line_breaks(
-                            &content,
-                            if output.is_empty() {
-                                0
-                            } else if is_right_after_properties {
-                                parse_string_to_usize(&options.other_gaps.after_properties, locale)?
-                                    + 1
-                            } else {
-                                parse_string_to_usize(
-                                    &options.heading_gaps.before_sub_headings,
-                                    locale,
-                                )? + 1
-                            },
-                            0,
-                        ));
-                    }
-                }
-
-                is_right_after_properties = false;
-                is_right_after_heading = true;
-                is_right_after_code_block = false;
-            }
-            MarkdownSection::Content(content) => {
-                output.push_str(&insert_line_breaks(
-                    &content,
-                    if output.is_empty() {
-                        0
-                    } else if is_right_after_properties {
-                        parse_string_to_usize(&options.other_gaps.after_properties, locale)? + 1
-                    } else if is_right_after_heading {
-                        parse_string_to_usize(
-                            &options.other_gaps.before_contents_after_headings,
-                            locale,
-                        )? + 1
-                    } else if is_right_after_code_block {
-                        parse_string_to_usize(
-                            &options.other_gaps.before_contents_after_code_blocks,
-                            locale,
-                        )? + 1
-                    } else {
-                        parse_string_to_usize(&options.other_gaps.before_contents, locale)? + 1
-                    },
-                    0,
-                ));
-
-                is_right_after_properties = false;
-                is_right_after_heading = false;
-                is_right_after_code_block = false;
-            }
-            MarkdownSection::Code(content) => {
-                output.push_str(&insert_line_breaks(
-                    &content,
-                    if output.is_empty() {
-                        0
-                    } else if is_right_after_properties {
-                        parse_string_to_usize(&options.other_gaps.after_properties, locale)? + 1
-                    } else if is_right_after_heading {
-                        parse_string_to_usize(
-                            &options.other_gaps.before_code_blocks_after_headings,
-                            locale,
-                        )? + 1
-                    } else {
-                        parse_string_to_usize(&options.other_gaps.before_code_blocks, locale)? + 1
-                    },
-                    0,
-                ));
-
-                is_right_after_properties = false;
-                is_right_after_heading = false;
-                is_right_after_code_block = true
-            }
-            MarkdownSection::Callout(content) => {
-                output.push_str(&insert_line_breaks(
-                    &content,
-                    if output.is_empty() {
-                        0
-                    } else if is_right_after_heading {
-                        parse_string_to_usize(
-                            &options.other_gaps.before_callouts_after_headings,
-                            locale,
-                        )? + 1
-                    } else {
-                        parse_string_to_usize(&options.other_gaps.before_callouts, locale)? + 1
-                    },
-                    0,
-                ));
-
-                is_right_after_properties = false;
-                is_right_after_heading = false;
-                is_right_after_code_block = false
-            }
+        // Headings keep their own gaps after every section except properties.
+        (MarkdownSection::Heading(_), Some(MarkdownSection::Property(_))) => {
+            &options.other_gaps.after_properties
+        }
+        (MarkdownSection::Heading(HeadingLevel::Top(_)), _) => {
+            &options.heading_gaps.before_top_level_headings
+        }
+        (MarkdownSection::Heading(HeadingLevel::FirstSub(_)), _) => {
+            &options.heading_gaps.before_first_sub_heading
+        }
+        (MarkdownSection::Heading(HeadingLevel::Sub(_)), _) => {
+            &options.heading_gaps.before_sub_headings
+        }
+
+        (MarkdownSection::Callout(_), Some(MarkdownSection::Heading(_))) => {
+            &options.other_gaps.before_callouts_after_headings
+        }
+        (MarkdownSection::Blockquote(_), Some(MarkdownSection::Heading(_))) => {
+            &options.other_gaps.before_blockquotes_after_headings
+        }
+
+        // Gaps after these sections take priority over gaps before the next section.
+        (_, Some(MarkdownSection::Math(_))) => &options.other_gaps.after_math_blocks,
+        (_, Some(MarkdownSection::ThematicBreak(_))) => &options.other_gaps.after_thematic_breaks,
+
+        // Gaps between sections of the same kind fall back to the gaps before them.
+        (MarkdownSection::Callout(_), Some(MarkdownSection::Callout(_)))
+            if options.other_gaps.between_callouts.is_some() =>
+        {
+            &options.other_gaps.between_callouts
+        }
+        (MarkdownSection::Callout(_), _) => &options.other_gaps.before_callouts,
+        (MarkdownSection::Blockquote(_), _) => &options.other_gaps.before_blockquotes,
+
+        (_, Some(MarkdownSection::Property(_))) => &options.other_gaps.after_properties,
+
+        // HTML blocks share gaps with content sections.
+        (
+            MarkdownSection::Content(_) | MarkdownSection::Html(_),
+            Some(MarkdownSection::Heading(_)),
+        ) => &options.other_gaps.before_contents_after_headings,
+        (
+            MarkdownSection::Content(_) | MarkdownSection::Html(_),
+            Some(MarkdownSection::Code(_) | MarkdownSection::IndentedCode(_)),
+        ) => &options.other_gaps.before_contents_after_code_blocks,
+        (MarkdownSection::Content(_) | MarkdownSection::Html(_), _) => {
+            &options.other_gaps.before_contents
+        }
+
+        (
+            MarkdownSection::Code(_) | MarkdownSection::IndentedCode(_),
+            Some(MarkdownSection::Heading(_)),
+        ) => &options.other_gaps.before_code_blocks_after_headings,
+        (
+            MarkdownSection::Code(_) | MarkdownSection::IndentedCode(_),
+            Some(MarkdownSection::Code(_) | MarkdownSection::IndentedCode(_)),
+        ) if options.other_gaps.between_code_blocks.is_some() => {
+            &options.other_gaps.between_code_blocks
+        }
+        (MarkdownSection::Code(_) | MarkdownSection::IndentedCode(_), _) => {
+            &options.other_gaps.before_code_blocks
+        }
+
+        (MarkdownSection::Math(_), _) => &options.other_gaps.before_math_blocks,
+
+        (MarkdownSection::Comment(_), _) => &options.other_gaps.before_comments,
+
+        (MarkdownSection::ThematicBreak(_), _) => &options.other_gaps.before_thematic_breaks,
+
+        (MarkdownSection::Footnote(_), _) => &options.other_gaps.before_footnotes,
+
+        (MarkdownSection::LinkDefinition(_), _) => &options.other_gaps.before_link_definitions,
+    };
+
+    let (min_gap, max_gap) = parse_gap_range(gap_option, locale)?;
+
+    Ok(original_gap.map_or(min_gap, |gap| gap.clamp(min_gap, max_gap)))
+}
+
+/// Reads the layout of an original document.
+pub fn get_original_layout(input: &str, sections: &[MarkdownSection]) -> OriginalLayout {
+    OriginalLayout {
+        gaps: get_original_gaps(input, sections),
+        ends_with_newline: input.ends_with('\n'),
+    }
+}
+
+/// Returns the number of empty lines before each section in the original document.
+/// Sections whose text is not found, like transformed ones, get `None`.
+fn get_original_gaps(input: &str, sections: &[MarkdownSection]) -> Vec<Option<usize>> {
+    let mut reading_position = 0;
+
+    sections
+        .iter()
+        .map(|section| {
+            let content = get_section_content(section);
+            let position = find_line_start(input, content, reading_position)
+                .or_else(|| find_line_start(input, content, 0))?;
+            reading_position = position + content.len();
+
+            let text_before = &input[..position];
+            let text_before = text_before.strip_suffix('\n').unwrap_or(text_before);
+
+            Some(
+                text_before
+                    .rsplit('\n')
+                    .take_while(|line| line.trim().is_empty())
+                    .count(),
+            )
+        })
+        .collect()
+}
+
+/// Finds a text that starts at the beginning of a line.
+fn find_line_start(input: &str, text: &str, from: usize) -> Option<usize> {
+    if text.is_empty() {
+        return None;
+    }
+
+    let mut search_position = from;
+    while let Some(offset) = input.get(search_position..)?.find(text) {
+        let position = search_position + offset;
+        if position == 0 || input[..position].ends_with('\n') {
+            return Some(position);
         }
+        search_position = position + 1;
     }
 
-    if preferences.options.format_options.insert_newline == Some(true) {
-        output.push('\n');
+    None
+}
+
+/// Returns the text of a section.
+fn get_section_content(section: &MarkdownSection) -> &str {
+    match section {
+        MarkdownSection::Property(content)
+        | MarkdownSection::Heading(
+            HeadingLevel::Top(content)
+            | HeadingLevel::FirstSub(content)
+            | HeadingLevel::Sub(content),
+        )
+        | MarkdownSection::Content(content)
+        | MarkdownSection::Code(content)
+        | MarkdownSection::IndentedCode(content)
+        | MarkdownSection::Math(content)
+        | MarkdownSection::Html(content)
+        | MarkdownSection::Comment(content)
+        | MarkdownSection::ThematicBreak(content)
+        | MarkdownSection::Footnote(content)
+        | MarkdownSection::LinkDefinition(content)
+        | MarkdownSection::Callout(content)
+        | MarkdownSection::Blockquote(content) => content,
     }
+}
 
-    Ok(output)
+/// Returns a thematic break in the preferred style.
+fn get_thematic_break(content: &str, style: Option<ThematicBreakStyle>) -> String {
+    match style {
+        Some(ThematicBreakStyle::Dashes) => String::from("---"),
+        Some(ThematicBreakStyle::Asterisks) => String::from("***"),
+        Some(ThematicBreakStyle::Underscores) => String::from("___"),
+        Some(ThematicBreakStyle::Preserve) | None => content.to_string(),
+    }
+}
+
+/// Limits the number of consecutive blank lines in a text.
+fn limit_blank_lines(content: &str, max_count: usize) -> String {
+    let mut blank_line_count = 0;
+
+    content
+        .split('\n')
+        .filter(|line| {
+            if line.trim().is_empty() {
+                blank_line_count += 1;
+                blank_line_count <= max_count
+            } else {
+                blank_line_count = 0;
+                true
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join("\n")
 }
 
 /// Inserts line breaks before and after an input.
@@ -175,6 +364,22 @@ pub fn insert_line_breaks(input: &str, before_count: usize, after_count: usize)
     format!("{}{}{}", line_breaks_before, input, line_breaks_after)
 }
 
+/// Parses a gap option, either an exact count (ex: `2`) or a range of counts. (ex: `1-3`)
+pub fn parse_gap_range(
+    input: &Option<String>,
+    locales: &Value,
+) -> Result<(usize, usize), Box<dyn Error>> {
+    if let Some((min, max)) = input.as_deref().and_then(|input| input.split_once('-')) {
+        let min = parse_string_to_usize(&Some(min.trim().to_string()), locales)?;
+        let max = parse_string_to_usize(&Some(max.trim().to_string()), locales)?;
+
+        return Ok((min.min(max), min.max(max)));
+    }
+
+    let gap = parse_string_to_usize(input, locales)?;
+    Ok((gap, gap))
+}
+
 /// Parses a usize value from a &str type argument.
 pub fn parse_string_to_usize(
     input: &Option<String>,
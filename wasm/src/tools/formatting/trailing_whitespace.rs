@@ -0,0 +1,84 @@
+use crate::option_schema::HardLineBreakStyle;
+use crate::tools::parsing::callouts::get_callout_header;
+use crate::tools::parsing::headings::alternate_headings::validation::{
+    get_valid_alternate_top_heading_level::get_alternate_heading_level,
+};
+
+/// Removes trailing spaces and tabs from every line of a text.
+/// Two or more trailing spaces before another line of the same paragraph are a hard line break,
+/// which is kept or converted to the preferred style.
+pub fn remove_trailing_whitespace(content: &str, style: Option<HardLineBreakStyle>) -> String {
+    let lines: Vec<&str> = content.split('\n').collect();
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let trimmed_line = line.trim_end_matches([' ', '\t']);
+            let is_hard_line_break = line.ends_with("  ")
+                && !is_blank_line(trimmed_line)
+                && lines
+                    .get(index + 1)
+                    .is_some_and(|next_line| is_paragraph_continuation(trimmed_line, next_line));
+
+            if !is_hard_line_break {
+                return trimmed_line.to_string();
+            }
+
+            match style {
+                Some(HardLineBreakStyle::Backslash) => format!("{}\\", trimmed_line),
+                Some(HardLineBreakStyle::Html) => format!("{}<br>", trimmed_line),
+                Some(HardLineBreakStyle::Preserve) | None => format!("{}  ", trimmed_line),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Checks if a line is blank, ignoring quote markers.
+fn is_blank_line(line: &str) -> bool {
+    line.trim_start_matches(|c: char| c == '>' || c.is_whitespace())
+        .is_empty()
+}
+
+/// Checks if the next line continues the paragraph of a line.
+/// List items, table rows, callout headers, alternate heading underlines
+/// and lines of another quote depth don't.
+fn is_paragraph_continuation(line: &str, next_line: &str) -> bool {
+    if is_blank_line(next_line)
+        || get_callout_header(line).is_some()
+        || get_callout_header(next_line).is_some()
+    {
+        return false;
+    }
+
+    let (depth, text) = split_quote_markers(line);
+    let (next_depth, next_text) = split_quote_markers(next_line);
+
+    depth == next_depth
+        && !text.starts_with('|')
+        && !next_text.starts_with('|')
+        && !is_list_item(next_text)
+        && get_alternate_heading_level(next_text.trim_end()).is_none()
+}
+
+/// Splits a line into the number of quote markers and the rest.
+fn split_quote_markers(line: &str) -> (usize, &str) {
+    let text = line.trim_start_matches(|c: char| c == '>' || c.is_whitespace());
+    let depth = line[..line.len() - text.len()].matches('>').count();
+
+    (depth, text)
+}
+
+/// Checks if a text starts a list item. (ex: `- `, `* `, `+ `, `1. `, `1) `)
+fn is_list_item(text: &str) -> bool {
+    let digit_count = text.chars().take_while(|c| c.is_ascii_digit()).count();
+    let marker_length = match text[digit_count..].chars().next() {
+        Some('-' | '*' | '+') if digit_count == 0 => 1,
+        Some('.' | ')') if digit_count > 0 => digit_count + 1,
+        _ => return false,
+    };
+    let rest = &text[marker_length..];
+
+    rest.is_empty() || rest.starts_with(' ') || rest.starts_with('\t')
+}
@@ -5,8 +5,15 @@ use crate::{
     Preferences,
 };
 
+pub mod callouts;
 mod contents;
+mod delimited_blocks;
+pub mod footnotes;
 pub mod headings;
+mod html_blocks;
+mod indented_code_blocks;
+pub mod link_definitions;
+mod thematic_breaks;
 
 #[derive(Debug)]
 struct ErrorInformation {
@@ -18,24 +25,65 @@ pub fn get_sections(
     input: &str,
     preferences: &Preferences,
 ) -> Result<Vec<MarkdownSection>, Box<dyn Error>> {
+    use super::parsing::callouts::get_quote_section;
     use super::parsing::contents::{append_a_line_break, finish_current_content_section};
+    use super::parsing::delimited_blocks::{
+        validate_closing_line, validate_opening_line, validate_single_line_block,
+        COMMENT_BLOCK_DELIMITER, MATH_BLOCK_DELIMITER,
+    };
+    use super::parsing::footnotes::{get_footnote_last_line, validate_footnote_definition};
     use super::parsing::headings::{
         alternate_headings::get_valid_alternate_heading_level,
         alternate_headings::validation::{
             validate_alternate_sub_heading, validate_alternate_top_heading,
         },
         get_top_heading_level,
+        hash_headings::normalization::normalize_hash_heading,
         hash_headings::validation::{validate_sub_hash_heading, validate_top_hash_heading},
     };
+    use super::parsing::html_blocks::get_html_block_last_line;
+    use super::parsing::indented_code_blocks::{
+        can_start_indented_code_block, get_indented_code_block_last_line,
+        validate_indented_code_block_line,
+    };
+    use super::parsing::link_definitions::validate_link_definition;
+    use super::parsing::thematic_breaks::validate_thematic_break;
 
     if input.is_empty() {
         return Ok(Vec::new());
     }
 
     let mut sections: Vec<MarkdownSection> = Vec::new();
-    let input_lines: Vec<&str> = input.trim().split('\n').collect();
-
-    let document_top_heading_level = get_top_heading_level(&input_lines);
+    // Leading blank lines are skipped, but the indentation of the first line is kept.
+    let input_lines: Vec<&str> = input
+        .trim_end()
+        .split('\n')
+        .skip_while(|line| line.trim().is_empty())
+        .collect();
+
+    // Hash headings are normalized before they are parsed,
+    // so that headings with leading spaces or without a space after hashes are detected.
+    let normalizes_hash_headings =
+        preferences.options.format_options.normalize_atx_headings == Some(true);
+    let normalized_hash_headings: Vec<Option<String>> = input_lines
+        .iter()
+        .map(|line| {
+            if normalizes_hash_headings {
+                normalize_hash_heading(line)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let document_top_heading_level = {
+        let heading_lines: Vec<&str> = input_lines
+            .iter()
+            .zip(&normalized_hash_headings)
+            .map(|(&line, heading)| heading.as_deref().unwrap_or(line))
+            .collect();
+        get_top_heading_level(&heading_lines)
+    };
 
     // Hash literals
     let mut top_heading_hash_literal = String::new();
@@ -54,7 +102,27 @@ pub fn get_sections(
     let mut is_reading_code_block = false;
     let mut current_code_block_backtick_count: Option<usize> = None;
 
-    // Callout sections.
+    // Math block sections.
+    let mut temp_math_block = String::new();
+    let mut is_reading_math_block = false;
+
+    // Comment block sections.
+    let mut temp_comment_block = String::new();
+    let mut is_reading_comment_block = false;
+
+    // HTML block sections.
+    let mut temp_html_block = String::new();
+    let mut html_block_last_line: Option<usize> = None;
+
+    // Footnote sections.
+    let mut temp_footnote = String::new();
+    let mut footnote_last_line: Option<usize> = None;
+
+    // Indented code block sections.
+    let mut temp_indented_code_block = String::new();
+    let mut indented_code_block_last_line: Option<usize> = None;
+
+    // Callout and blockquote sections.
     let mut temp_callout = String::new();
     let mut is_reading_callout = false;
 
@@ -74,7 +142,12 @@ pub fn get_sections(
         if line.is_empty()
             && !is_reading_content_section
             && !is_reading_code_block
+            && !is_reading_math_block
+            && !is_reading_comment_block
             && !is_reading_callout
+            && indented_code_block_last_line.is_none()
+            && html_block_last_line.is_none()
+            && footnote_last_line.is_none()
         {
             continue;
         }
@@ -82,9 +155,10 @@ pub fn get_sections(
 
         // Variables for detecting syntax.
         // - Headings
-        let is_hash_symbol_only = line.chars().all(|item| item == '#');
+        let heading_line = normalized_hash_headings[index].as_deref().unwrap_or(line);
+        let is_hash_symbol_only = heading_line.chars().all(|item| item == '#');
         let is_valid_hash_symbol_syntax =
-            line.starts_with('#') && (line.contains("# ") || is_hash_symbol_only);
+            heading_line.starts_with('#') && (heading_line.contains("# ") || is_hash_symbol_only);
         let is_reading_a_heading = is_hash_symbol_only || is_valid_hash_symbol_syntax;
         let alternate_heading_level: Option<usize> =
             get_valid_alternate_heading_level(&input_lines, index);
@@ -97,6 +171,13 @@ pub fn get_sections(
                     && !line.trim().is_empty()));
         // - Code blocks
         let is_valid_code_block_syntax_line = line.starts_with("```");
+        // - Math blocks
+        let is_valid_math_block_syntax_line = validate_opening_line(line, MATH_BLOCK_DELIMITER);
+        // - Comment blocks
+        let is_valid_comment_block_syntax_line =
+            validate_opening_line(line, COMMENT_BLOCK_DELIMITER);
+        // - Indented code blocks
+        let is_valid_indented_code_block_line = validate_indented_code_block_line(line);
 
         // Read properties.
         if sections.is_empty()
@@ -138,8 +219,42 @@ pub fn get_sections(
             }
         }
 
-        // Read callouts
-        if is_valid_callout_syntax_line || is_reading_callout {
+        // Read HTML blocks.
+        if html_block_last_line.is_none()
+            && !is_reading_callout
+            && !is_reading_code_block
+            && !is_reading_math_block
+            && !is_reading_comment_block
+        {
+            if let Some(last_line_index) = get_html_block_last_line(&input_lines, index) {
+                finish_current_content_section(
+                    &mut is_reading_content_section,
+                    &mut sections,
+                    &mut temp_content_section,
+                );
+
+                // Enter an HTML block.
+                html_block_last_line = Some(last_line_index);
+            }
+        }
+        if let Some(last_line_index) = html_block_last_line {
+            append_a_line_break(&mut temp_html_block, line);
+
+            if index == last_line_index {
+                // Exit an HTML block.
+                sections.push(MarkdownSection::Html(temp_html_block.clone()));
+                temp_html_block.clear();
+                html_block_last_line = None;
+            }
+            continue;
+        }
+
+        // Read callouts and blockquotes.
+        if (is_valid_callout_syntax_line || is_reading_callout)
+            && !is_reading_code_block
+            && !is_reading_math_block
+            && !is_reading_comment_block
+        {
             if is_valid_callout_syntax_line {
                 finish_current_content_section(
                     &mut is_reading_content_section,
@@ -153,7 +268,7 @@ pub fn get_sections(
                 // When faced with a code block or a heading,
                 // immediately stop parsing a callout section.
                 is_reading_callout = false;
-                sections.push(MarkdownSection::Callout(temp_callout.clone()));
+                sections.push(get_quote_section(temp_callout.clone()));
                 temp_callout.clear();
             } else {
                 let is_reading_the_last_line = index == input_lines.len() - 1;
@@ -169,7 +284,7 @@ pub fn get_sections(
                     || is_reading_the_last_line
                 {
                     is_reading_callout = false;
-                    sections.push(MarkdownSection::Callout(temp_callout.clone()));
+                    sections.push(get_quote_section(temp_callout.clone()));
                     temp_callout.clear();
                 } else if is_valid_callout_syntax_line {
                     is_reading_callout = true;
@@ -186,7 +301,10 @@ pub fn get_sections(
         }
 
         // Read code blocks.
-        if is_valid_code_block_syntax_line || is_reading_code_block {
+        if (is_valid_code_block_syntax_line || is_reading_code_block)
+            && !is_reading_math_block
+            && !is_reading_comment_block
+        {
             finish_current_content_section(
                 &mut is_reading_content_section,
                 &mut sections,
@@ -235,10 +353,149 @@ pub fn get_sections(
             }
         }
 
+        // Read math blocks.
+        if (is_valid_math_block_syntax_line || is_reading_math_block) && !is_reading_comment_block {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            if !is_reading_math_block {
+                // Enter a math block.
+                error_information.reading_section_starting_line = index;
+                temp_math_block.push_str(line);
+
+                // A math block can also be written in a single line. (ex: $$x^2$$)
+                if validate_single_line_block(line, MATH_BLOCK_DELIMITER) {
+                    sections.push(MarkdownSection::Math(temp_math_block.clone()));
+                    temp_math_block.clear();
+                } else {
+                    is_reading_math_block = true;
+                }
+                continue;
+            }
+
+            // Keep reading the math block.
+            temp_math_block.push('\n');
+            temp_math_block.push_str(line);
+
+            if validate_closing_line(line, MATH_BLOCK_DELIMITER) {
+                // Exit a math block.
+                sections.push(MarkdownSection::Math(temp_math_block.clone()));
+                temp_math_block.clear();
+                is_reading_math_block = false;
+            }
+            continue;
+        }
+
+        // Read comment blocks.
+        if is_valid_comment_block_syntax_line || is_reading_comment_block {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            if !is_reading_comment_block {
+                // Enter a comment block.
+                error_information.reading_section_starting_line = index;
+                temp_comment_block.push_str(line);
+
+                if validate_single_line_block(line, COMMENT_BLOCK_DELIMITER) {
+                    sections.push(MarkdownSection::Comment(temp_comment_block.clone()));
+                    temp_comment_block.clear();
+                } else {
+                    is_reading_comment_block = true;
+                }
+                continue;
+            }
+
+            // Keep reading the comment block.
+            temp_comment_block.push('\n');
+            temp_comment_block.push_str(line);
+
+            if validate_closing_line(line, COMMENT_BLOCK_DELIMITER) {
+                // Exit a comment block.
+                sections.push(MarkdownSection::Comment(temp_comment_block.clone()));
+                temp_comment_block.clear();
+                is_reading_comment_block = false;
+            }
+            continue;
+        }
+
+        // Read footnotes.
+        if footnote_last_line.is_none() && validate_footnote_definition(line) {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            // Enter a footnote.
+            footnote_last_line = Some(get_footnote_last_line(&input_lines, index));
+        }
+        if let Some(last_line_index) = footnote_last_line {
+            append_a_line_break(&mut temp_footnote, line);
+
+            if index == last_line_index {
+                // Exit a footnote.
+                sections.push(MarkdownSection::Footnote(temp_footnote.clone()));
+                temp_footnote.clear();
+                footnote_last_line = None;
+            }
+            continue;
+        }
+
+        // Read link reference definitions.
+        // They cannot interrupt a paragraph.
+        let can_start_link_definition =
+            temp_content_section.is_empty() || input_lines[index - 1].trim().is_empty();
+        if can_start_link_definition && validate_link_definition(line) {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            sections.push(MarkdownSection::LinkDefinition(line.to_string()));
+            continue;
+        }
+
+        // Read indented code blocks.
+        if indented_code_block_last_line.is_none()
+            && is_valid_indented_code_block_line
+            && can_start_indented_code_block(&temp_content_section)
+        {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            // Enter an indented code block.
+            indented_code_block_last_line =
+                Some(get_indented_code_block_last_line(&input_lines, index));
+        }
+        if let Some(last_line_index) = indented_code_block_last_line {
+            append_a_line_break(&mut temp_indented_code_block, line);
+
+            if index == last_line_index {
+                // Exit an indented code block.
+                sections.push(MarkdownSection::IndentedCode(
+                    temp_indented_code_block.clone(),
+                ));
+                temp_indented_code_block.clear();
+                indented_code_block_last_line = None;
+            }
+            continue;
+        }
+
         // Read hash headings.
         if is_valid_hash_symbol_syntax {
             if let Some(document_top_heading_level) = document_top_heading_level {
-                let is_top_level = validate_top_hash_heading(line, &top_heading_hash_literal);
+                let is_top_level =
+                    validate_top_hash_heading(heading_line, &top_heading_hash_literal);
 
                 if is_top_level {
                     finish_current_content_section(
@@ -248,14 +505,15 @@ pub fn get_sections(
                     );
 
                     sections.push(MarkdownSection::Heading(HeadingLevel::Top(
-                        line.to_string(),
+                        heading_line.to_string(),
                     )));
 
                     current_heading_level = document_top_heading_level;
                     continue;
                 } else {
-                    let is_sub_heading = validate_sub_hash_heading(line, is_hash_symbol_only);
-                    let heading_level = line.chars().take_while(|&c| c == '#').count();
+                    let is_sub_heading =
+                        validate_sub_hash_heading(heading_line, is_hash_symbol_only);
+                    let heading_level = heading_line.chars().take_while(|&c| c == '#').count();
 
                     if is_sub_heading {
                         finish_current_content_section(
@@ -266,11 +524,11 @@ pub fn get_sections(
 
                         if heading_level > current_heading_level {
                             sections.push(MarkdownSection::Heading(HeadingLevel::FirstSub(
-                                line.to_string(),
+                                heading_line.to_string(),
                             )));
                         } else {
                             sections.push(MarkdownSection::Heading(HeadingLevel::Sub(
-                                line.to_string(),
+                                heading_line.to_string(),
                             )));
                         }
 
@@ -282,7 +540,14 @@ pub fn get_sections(
         }
 
         // Read alternate headings.
-        if let Some(alternate_heading_level) = alternate_heading_level {
+        // The title line must be a part of the current "content" section,
+        // otherwise it belongs to another section. (ex: An indented code block)
+        let is_previous_line_in_content_section = index > 0
+            && !temp_content_section.is_empty()
+            && temp_content_section.rsplit('\n').next() == input_lines.get(index - 1).copied();
+        if let Some(alternate_heading_level) =
+            alternate_heading_level.filter(|_| is_previous_line_in_content_section)
+        {
             if is_reading_code_block || is_reading_property_block {
                 continue;
             }
@@ -342,12 +607,31 @@ pub fn get_sections(
             }
         }
 
+        // Read thematic breaks.
+        // A dash-only line right after a paragraph is an (invalid) alternate heading syntax,
+        // so it stays in the current "content" section.
+        let is_alternate_heading_syntax_after_paragraph = line.chars().all(|c| c == '-')
+            && is_previous_line_in_content_section
+            && !input_lines[index - 1].trim().is_empty();
+        if validate_thematic_break(line) && !is_alternate_heading_syntax_after_paragraph {
+            finish_current_content_section(
+                &mut is_reading_content_section,
+                &mut sections,
+                &mut temp_content_section,
+            );
+
+            sections.push(MarkdownSection::ThematicBreak(line.to_string()));
+            continue;
+        }
+
         // Read contents.
         if is_reading_content_section {
             error_information.reading_section_starting_line = index;
 
             check_parsing_error(
                 is_reading_code_block,
+                is_reading_math_block,
+                is_reading_comment_block,
                 is_reading_property_block,
                 preferences,
                 &error_information,
@@ -367,6 +651,8 @@ pub fn get_sections(
 
     check_parsing_error(
         is_reading_code_block,
+        is_reading_math_block,
+        is_reading_comment_block,
         is_reading_property_block,
         preferences,
         &error_information,
@@ -378,13 +664,19 @@ pub fn get_sections(
 /// Returns an error if the document is invalid.
 fn check_parsing_error(
     is_reading_code_block: bool,
+    is_reading_math_block: bool,
+    is_reading_comment_block: bool,
     is_reading_property_block: bool,
     preferences: &Preferences,
     error_information: &ErrorInformation,
 ) -> Result<(), Box<dyn Error>> {
     use crate::utils::{get_locale_string, LocaleCategory};
 
-    if is_reading_code_block || is_reading_property_block {
+    if is_reading_code_block
+        || is_reading_math_block
+        || is_reading_comment_block
+        || is_reading_property_block
+    {
         let error_message = if let Some(true) = preferences
             .options
             .other_options
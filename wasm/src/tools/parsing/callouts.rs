@@ -0,0 +1,44 @@
+use crate::tools::tokens::{CalloutFold, CalloutHeader, MarkdownSection};
+
+/// Returns a callout section if the first line is a callout header,
+/// or a blockquote section otherwise.
+pub fn get_quote_section(content: String) -> MarkdownSection {
+    let first_line = content.split('\n').next().unwrap_or_default();
+
+    if get_callout_header(first_line).is_some() {
+        MarkdownSection::Callout(content)
+    } else {
+        MarkdownSection::Blockquote(content)
+    }
+}
+
+/// Parses the first line of a callout. (ex: `> [!note]- Title`)
+/// Returns `None` for plain blockquotes.
+pub fn get_callout_header(line: &str) -> Option<CalloutHeader> {
+    let type_start = line.find("[!")?;
+    let prefix = &line[..type_start];
+    if !prefix.contains('>') || !prefix.chars().all(|c| c == '>' || c.is_whitespace()) {
+        return None;
+    }
+
+    let after_opening = &line[type_start + 2..];
+    let type_end = after_opening.find(']')?;
+    let callout_type = &after_opening[..type_end];
+    if callout_type.is_empty() || callout_type.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let rest = &after_opening[type_end + 1..];
+    let (fold, title) = match rest.chars().next() {
+        Some('+') => (Some(CalloutFold::Expanded), &rest[1..]),
+        Some('-') => (Some(CalloutFold::Collapsed), &rest[1..]),
+        _ => (None, rest),
+    };
+
+    Some(CalloutHeader {
+        prefix: prefix.to_string(),
+        callout_type: callout_type.to_string(),
+        fold,
+        title: title.trim().to_string(),
+    })
+}
@@ -0,0 +1,39 @@
+/// Opens and closes a math block.
+pub const MATH_BLOCK_DELIMITER: &str = "$$";
+/// Opens and closes a comment block.
+pub const COMMENT_BLOCK_DELIMITER: &str = "%%";
+
+/// Checks if a line opens a delimited block, or is a single line block.
+/// A line that closes the block and continues with other text is a part of a paragraph.
+/// (ex: `$$x$$ is the formula`)
+///
+/// Math blocks also need nothing after the delimiter, except a LaTeX command. (ex: `$$\begin{aligned}`)
+pub fn validate_opening_line(line: &str, delimiter: &str) -> bool {
+    if validate_single_line_block(line, delimiter) {
+        return true;
+    }
+
+    let rest = match line.trim_end().strip_prefix(delimiter) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    if rest.contains(delimiter) {
+        return false;
+    }
+
+    delimiter != MATH_BLOCK_DELIMITER || rest.trim().is_empty() || rest.starts_with('\\')
+}
+
+/// Checks if a line closes a delimited block.
+pub fn validate_closing_line(line: &str, delimiter: &str) -> bool {
+    line.trim_end().ends_with(delimiter)
+}
+
+/// Checks if a delimited block is written in a single line. (ex: $$x^2$$)
+pub fn validate_single_line_block(line: &str, delimiter: &str) -> bool {
+    let trimmed_line = line.trim_end();
+
+    trimmed_line.starts_with(delimiter)
+        && trimmed_line.len() >= delimiter.len() * 2
+        && trimmed_line.ends_with(delimiter)
+}
@@ -0,0 +1,36 @@
+/// Checks if a line starts a footnote definition. (ex: `[^1]: Footnote`)
+pub fn validate_footnote_definition(line: &str) -> bool {
+    get_footnote_definition_label(line).is_some()
+}
+
+/// Returns the label of a footnote definition.
+pub fn get_footnote_definition_label(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("[^")?;
+    let closing_position = rest.find("]:")?;
+    let label = &rest[..closing_position];
+
+    if label.is_empty() || label.contains(|c: char| c.is_whitespace() || c == '[' || c == ']') {
+        None
+    } else {
+        Some(label)
+    }
+}
+
+/// Returns the index of the last line of a footnote definition.
+/// Indented lines after the first line are a part of the definition.
+pub fn get_footnote_last_line(input_lines: &[&str], starting_index: usize) -> usize {
+    let mut last_line_index = starting_index;
+
+    for (index, &line) in input_lines.iter().enumerate().skip(starting_index + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with([' ', '\t']) {
+            break;
+        }
+
+        last_line_index = index;
+    }
+
+    last_line_index
+}
@@ -2,13 +2,53 @@
 pub fn get_top_heading_level(input_lines: &[&str]) -> Option<usize> {
     use self::alternate_headings::get_valid_alternate_heading_level;
     use self::hash_headings::validation::validate_hash_heading;
+    use super::delimited_blocks::{
+        validate_closing_line, validate_opening_line, validate_single_line_block,
+        COMMENT_BLOCK_DELIMITER, MATH_BLOCK_DELIMITER,
+    };
+    use super::html_blocks::get_html_block_last_line;
 
     let mut top_heading_level = usize::MAX;
 
     let mut is_reading_code_block = false;
     let mut reading_code_block_backtick_count: Option<usize> = None;
 
+    // Math blocks and comment blocks.
+    let mut reading_block_delimiter: Option<&str> = None;
+    let mut html_block_last_line: Option<usize> = None;
+
     for (index, &line) in input_lines.iter().enumerate() {
+        // Skip HTML blocks.
+        if let Some(last_line_index) = html_block_last_line {
+            if index == last_line_index {
+                html_block_last_line = None;
+            }
+            continue;
+        }
+
+        // Skip math blocks and comment blocks.
+        if !is_reading_code_block {
+            if let Some(delimiter) = reading_block_delimiter {
+                if validate_closing_line(line, delimiter) {
+                    // Exit the block.
+                    reading_block_delimiter = None;
+                }
+                continue;
+            }
+
+            let opening_delimiter = [MATH_BLOCK_DELIMITER, COMMENT_BLOCK_DELIMITER]
+                .iter()
+                .copied()
+                .find(|delimiter| validate_opening_line(line, delimiter));
+            if let Some(delimiter) = opening_delimiter {
+                // Enter the block, unless it's written in a single line.
+                if !validate_single_line_block(line, delimiter) {
+                    reading_block_delimiter = Some(delimiter);
+                }
+                continue;
+            }
+        }
+
         let current_line_backtick_count = line.chars().filter(|&c| c == '`').count();
 
         // Skip code blocks.
@@ -34,6 +74,14 @@ pub fn get_top_heading_level(input_lines: &[&str]) -> Option<usize> {
             continue;
         }
 
+        if let Some(last_line_index) = get_html_block_last_line(input_lines, index) {
+            // Enter an HTML block.
+            if last_line_index > index {
+                html_block_last_line = Some(last_line_index);
+            }
+            continue;
+        }
+
         // Parse hash headings.
         let is_valid_hash_heading = validate_hash_heading(input_lines[index]);
 
@@ -85,6 +133,83 @@ pub mod hash_headings {
             line.contains("# ") || only_contains_header_symbols
         }
     }
+
+    pub mod normalization {
+        /// Returns a hash heading with one space after its hashes,
+        /// without closing hashes, leading spaces and repeated spaces.
+        /// Up to 3 leading spaces are allowed, and the space after 2 or more hashes can be missing.
+        /// (`#tag` is not a heading.)
+        pub fn normalize_hash_heading(line: &str) -> Option<String> {
+            let indentation = line.chars().take_while(|&c| c == ' ').count();
+            if indentation > 3 {
+                return None;
+            }
+
+            let line = &line[indentation..];
+            let level = line.chars().take_while(|&c| c == '#').count();
+            if level == 0 || level > 6 {
+                return None;
+            }
+
+            let rest = &line[level..];
+            let has_space = rest.is_empty() || rest.starts_with(' ') || rest.starts_with('\t');
+            if !has_space && level < 2 {
+                return None;
+            }
+
+            let hashes = "#".repeat(level);
+            let title = remove_closing_hashes(rest.trim());
+            if title.is_empty() {
+                return Some(hashes);
+            }
+
+            Some(format!("{} {}", hashes, collapse_spaces(title)))
+        }
+
+        /// Removes a closing sequence. (ex: `Title ##` -> `Title`)
+        /// Hashes that are not separated by a space are a part of the title. (ex: `C#`)
+        pub fn remove_closing_hashes(title: &str) -> &str {
+            let without_hashes = title.trim_end_matches('#');
+            if without_hashes.is_empty() {
+                return without_hashes;
+            }
+            if without_hashes.ends_with(' ') || without_hashes.ends_with('\t') {
+                return without_hashes.trim_end();
+            }
+
+            title
+        }
+
+        /// Collapses runs of spaces into one space, outside of inline code.
+        fn collapse_spaces(title: &str) -> String {
+            let mut result = String::with_capacity(title.len());
+            let mut opening_backtick_count: Option<usize> = None;
+            let mut backtick_count = 0;
+
+            for char in title.chars() {
+                if char == '`' {
+                    backtick_count += 1;
+                    result.push(char);
+                    continue;
+                }
+                if backtick_count > 0 {
+                    opening_backtick_count = match opening_backtick_count {
+                        None => Some(backtick_count),
+                        Some(count) if count == backtick_count => None,
+                        other => other,
+                    };
+                    backtick_count = 0;
+                }
+
+                if char == ' ' && opening_backtick_count.is_none() && result.ends_with(' ') {
+                    continue;
+                }
+                result.push(char);
+            }
+
+            result
+        }
+    }
 }
 
 pub mod alternate_headings {
@@ -121,6 +246,7 @@ pub mod alternate_headings {
             reading_index: usize,
         ) -> bool {
             use self::get_valid_alternate_top_heading_level::get_alternate_heading_level;
+            use crate::tools::parsing::thematic_breaks::validate_thematic_break;
 
             let mut is_reading_syntax = true;
             let mut is_reading_title = false;
@@ -143,7 +269,9 @@ pub mod alternate_headings {
 
                     return false;
                 } else if is_reading_title {
-                    if super::super::hash_headings::validation::validate_hash_heading(line) {
+                    if super::super::hash_headings::validation::validate_hash_heading(line)
+                        || validate_thematic_break(line)
+                    {
                         return false;
                     }
                     if index == 0 {
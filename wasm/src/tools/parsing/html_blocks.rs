@@ -0,0 +1,168 @@
+/// HTML tags that start an HTML block. (CommonMark block-level tags)
+const BLOCK_TAG_NAMES: [&str; 66] = [
+    "address",
+    "article",
+    "aside",
+    "base",
+    "basefont",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "iframe",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "menuitem",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "pre",
+    "script",
+    "search",
+    "section",
+    "style",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "textarea",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+];
+
+/// Returns the index of the last line of an HTML block,
+/// if an HTML block starts from the reading line.
+///
+/// HTML comments end with `-->`.
+/// Other HTML blocks end with the matching closing tag.
+/// If there is no matching closing tag, the block ends before the next empty line.
+pub fn get_html_block_last_line(input_lines: &[&str], reading_index: usize) -> Option<usize> {
+    let line = input_lines.get(reading_index)?;
+
+    if line.starts_with("<!--") {
+        return input_lines
+            .iter()
+            .enumerate()
+            .skip(reading_index)
+            .find(|(index, item)| {
+                let searching_part = if *index == reading_index {
+                    &item[4..]
+                } else {
+                    item
+                };
+                searching_part.contains("-->")
+            })
+            .map(|(index, _)| index);
+    }
+
+    let tag_name = get_block_tag_name(line)?;
+
+    // Find the matching closing tag.
+    let mut depth = 0;
+    for (index, &item) in input_lines.iter().enumerate().skip(reading_index) {
+        let lowercase_line = item.to_lowercase();
+
+        depth += count_opening_tags(&lowercase_line, &tag_name);
+        depth -= lowercase_line.matches(&format!("</{}>", tag_name)).count() as isize;
+
+        if depth <= 0 {
+            return Some(index);
+        }
+    }
+
+    // Fall back to the end of the current paragraph.
+    let last_line_index = input_lines
+        .iter()
+        .enumerate()
+        .skip(reading_index)
+        .take_while(|(_, item)| !item.trim().is_empty())
+        .last()
+        .map_or(reading_index, |(index, _)| index);
+
+    Some(last_line_index)
+}
+
+/// Returns the lowercase tag name if a line starts with a block-level opening tag.
+fn get_block_tag_name(line: &str) -> Option<String> {
+    let rest = line.strip_prefix('<')?;
+    let tag_name: String = rest
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+
+    if validate_tag_name_end(&rest[tag_name.len()..])
+        && BLOCK_TAG_NAMES.contains(&tag_name.as_str())
+    {
+        Some(tag_name)
+    } else {
+        None
+    }
+}
+
+/// Counts opening tags with the given name. Self-closing tags are ignored.
+fn count_opening_tags(lowercase_line: &str, tag_name: &str) -> isize {
+    let opening_tag = format!("<{}", tag_name);
+    let mut count = 0;
+
+    for (position, _) in lowercase_line.match_indices(&opening_tag) {
+        let rest = &lowercase_line[position + opening_tag.len()..];
+        if !validate_tag_name_end(rest) {
+            continue;
+        }
+
+        let is_self_closing = rest
+            .find('>')
+            .is_some_and(|closing_position| rest[..closing_position].ends_with('/'));
+        if !is_self_closing {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// Checks if a tag name ends right before the given text.
+fn validate_tag_name_end(rest: &str) -> bool {
+    rest.chars()
+        .next()
+        .is_none_or(|c| c.is_whitespace() || c == '>' || c == '/')
+}
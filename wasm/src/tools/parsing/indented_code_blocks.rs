@@ -0,0 +1,56 @@
+/// Checks if a line is indented enough to be a part of an indented code block.
+pub fn validate_indented_code_block_line(line: &str) -> bool {
+    (line.starts_with("    ") || line.starts_with('\t')) && !line.trim().is_empty()
+}
+
+/// Checks if an indented code block can start after the current "content" section.
+/// An indented code block can't interrupt a paragraph or continue a list item.
+pub fn can_start_indented_code_block(temp_content_section: &str) -> bool {
+    if temp_content_section.is_empty() {
+        return true;
+    }
+    if !temp_content_section.ends_with('\n') {
+        // The previous line is a part of a paragraph.
+        return false;
+    }
+
+    let last_non_blank_line = temp_content_section
+        .split('\n')
+        .rev()
+        .find(|line| !line.trim().is_empty());
+
+    match last_non_blank_line {
+        Some(line) => !line.starts_with([' ', '\t']) && !validate_list_item(line),
+        None => true,
+    }
+}
+
+/// Returns the index of the last line of an indented code block.
+/// Trailing empty lines are not a part of the code block.
+pub fn get_indented_code_block_last_line(input_lines: &[&str], starting_index: usize) -> usize {
+    let mut last_line_index = starting_index;
+
+    for (index, &line) in input_lines.iter().enumerate().skip(starting_index) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !validate_indented_code_block_line(line) {
+            break;
+        }
+
+        last_line_index = index;
+    }
+
+    last_line_index
+}
+
+/// Checks if a line starts a list item.
+fn validate_list_item(line: &str) -> bool {
+    if line.starts_with("- ") || line.starts_with("* ") || line.starts_with("+ ") {
+        return true;
+    }
+
+    let digit_count = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    digit_count > 0
+        && (line[digit_count..].starts_with(". ") || line[digit_count..].starts_with(") "))
+}
@@ -0,0 +1,60 @@
+/// Validates link reference definition syntax. (ex: `[label]: https://example.com "Title"`)
+pub fn validate_link_definition(line: &str) -> bool {
+    let label = match get_link_definition_label(line) {
+        Some(label) => label,
+        None => return false,
+    };
+
+    // Skip the label with its brackets and the colon.
+    let rest = line.trim_start()[label.len() + 3..].trim();
+
+    let title = if let Some(after_opening) = rest.strip_prefix('<') {
+        match after_opening.find('>') {
+            Some(closing_position) => &after_opening[closing_position + 1..],
+            None => return false,
+        }
+    } else {
+        match rest.find(char::is_whitespace) {
+            Some(position) => &rest[position..],
+            None => "",
+        }
+    };
+
+    if rest.is_empty() {
+        return false;
+    }
+    if title.is_empty() {
+        return true;
+    }
+    if !title.starts_with(char::is_whitespace) {
+        return false;
+    }
+
+    validate_link_title(title.trim())
+}
+
+/// Returns the label of a link reference definition.
+pub fn get_link_definition_label(line: &str) -> Option<&str> {
+    let leading_space_count = line.chars().take_while(|&c| c == ' ').count();
+    if leading_space_count > 3 {
+        return None;
+    }
+
+    let rest = line.trim_start().strip_prefix('[')?;
+    let closing_position = rest.find("]:")?;
+    let label = &rest[..closing_position];
+
+    if label.trim().is_empty() || label.starts_with('^') || label.contains(['[', ']']) {
+        None
+    } else {
+        Some(label)
+    }
+}
+
+/// Validates the optional title of a link reference definition.
+fn validate_link_title(title: &str) -> bool {
+    title.len() >= 2
+        && [('"', '"'), ('\'', '\''), ('(', ')')]
+            .iter()
+            .any(|&(opening, closing)| title.starts_with(opening) && title.ends_with(closing))
+}
@@ -0,0 +1,18 @@
+/// Validates thematic break syntax. (ex: `---`, `***`, `___`, `- - -`)
+pub fn validate_thematic_break(line: &str) -> bool {
+    let leading_space_count = line.chars().take_while(|&c| c == ' ').count();
+    if leading_space_count > 3 {
+        return false;
+    }
+
+    let symbols: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+
+    match symbols.first() {
+        Some(&first_symbol) => {
+            matches!(first_symbol, '-' | '*' | '_')
+                && symbols.len() >= 3
+                && symbols.iter().all(|&c| c == first_symbol)
+        }
+        None => false,
+    }
+}
@@ -11,5 +11,31 @@ pub enum MarkdownSection {
     Heading(HeadingLevel),
     Content(String),
     Code(String),
+    IndentedCode(String),
+    Math(String),
+    Html(String),
+    Comment(String),
+    ThematicBreak(String),
+    Footnote(String),
+    LinkDefinition(String),
     Callout(String),
+    Blockquote(String),
+}
+
+/// Header of a callout. (ex: `> [!note]- Title`)
+#[derive(Debug, PartialEq)]
+pub struct CalloutHeader {
+    /// Text before the type, including quote markers.
+    pub prefix: String,
+    pub callout_type: String,
+    pub fold: Option<CalloutFold>,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalloutFold {
+    /// `+`
+    Expanded,
+    /// `-`
+    Collapsed,
 }
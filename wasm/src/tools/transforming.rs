@@ -0,0 +1,146 @@
+use std::error::Error;
+
+use crate::{
+    option_schema::{
+        CalloutTypeCase, HeadingCase, HeadingStyle, TableOfContentsLinkStyle, TopHeadingLevel,
+    },
+    tools::tokens::MarkdownSection,
+    Preferences,
+};
+
+mod callouts;
+mod footnotes;
+mod headings;
+mod link_definitions;
+mod table_of_contents;
+
+/// Applies transforms that change the order or the text of sections.
+pub fn get_transformed_sections(
+    sections: Vec<MarkdownSection>,
+    preferences: &Preferences,
+) -> Result<Vec<MarkdownSection>, Box<dyn Error>> {
+    use self::callouts::{
+        format_quote_interiors, insert_spaces_after_callout_markers, normalize_callout_types,
+    };
+    use self::footnotes::{move_footnotes_to_end, renumber_footnotes};
+    use self::headings::{
+        change_heading_case, convert_heading_styles, get_small_words, number_headings,
+        remove_heading_trailing_punctuation, repair_heading_levels,
+        DEFAULT_HEADING_TRAILING_PUNCTUATION, DEFAULT_SMALL_WORDS,
+    };
+    use self::link_definitions::{
+        deduplicate_link_definitions, move_link_definitions, sort_link_definitions,
+    };
+    use self::table_of_contents::generate_table_of_contents;
+    use super::formatting::parse_string_to_usize;
+
+    let mut sections = sections;
+    let options = &preferences.options;
+
+    let case = options
+        .format_options
+        .callout_type_case
+        .unwrap_or(CalloutTypeCase::Preserve);
+    let aliases = options
+        .format_options
+        .callout_type_aliases
+        .as_deref()
+        .unwrap_or_default();
+    if case != CalloutTypeCase::Preserve || !aliases.trim().is_empty() {
+        sections = normalize_callout_types(sections, case, aliases);
+    }
+    if options.format_options.format_quote_interiors == Some(true) {
+        sections = format_quote_interiors(sections, preferences)?;
+    }
+    if options.format_options.ensure_space_after_callout_markers == Some(true) {
+        sections = insert_spaces_after_callout_markers(sections);
+    }
+
+    if options.format_options.deduplicate_link_definitions == Some(true) {
+        sections = deduplicate_link_definitions(sections);
+    }
+    if let Some(position) = options.format_options.link_definition_position {
+        sections = move_link_definitions(sections, position);
+    }
+    if options.format_options.sort_link_definitions == Some(true) {
+        sections = sort_link_definitions(sections);
+    }
+
+    if let Some(style) = options
+        .format_options
+        .heading_style
+        .filter(|&style| style != HeadingStyle::Preserve)
+    {
+        sections = convert_heading_styles(sections, style, preferences);
+    }
+    let top_heading_level = match options.format_options.top_heading_level {
+        Some(TopHeadingLevel::H1) => Some(1),
+        Some(TopHeadingLevel::H2) => Some(2),
+        Some(TopHeadingLevel::Preserve) | None => None,
+    };
+    let demote_extra_h1_headings = options.format_options.demote_extra_h1_headings == Some(true);
+    let fix_skipped_heading_levels =
+        options.format_options.fix_skipped_heading_levels == Some(true);
+    if demote_extra_h1_headings || fix_skipped_heading_levels || top_heading_level.is_some() {
+        sections = repair_heading_levels(
+            sections,
+            demote_extra_h1_headings,
+            fix_skipped_heading_levels,
+            top_heading_level,
+        );
+    }
+    if options.format_options.remove_heading_trailing_punctuation == Some(true) {
+        let punctuation = match options
+            .format_options
+            .heading_trailing_punctuation
+            .as_deref()
+        {
+            Some(input) if !input.trim().is_empty() => input.trim(),
+            _ => DEFAULT_HEADING_TRAILING_PUNCTUATION,
+        };
+        sections = remove_heading_trailing_punctuation(sections, punctuation);
+    }
+    if let Some(case) = options
+        .format_options
+        .heading_case
+        .filter(|&case| case != HeadingCase::Preserve)
+    {
+        let small_words = match options.format_options.heading_case_small_words.as_deref() {
+            Some(input) if !input.trim().is_empty() => input,
+            _ => DEFAULT_SMALL_WORDS,
+        };
+        sections = change_heading_case(sections, case, &get_small_words(small_words));
+    }
+    if options.format_options.number_headings == Some(true) {
+        let start_level = match &options.format_options.heading_numbering_start_level {
+            Some(input) if !input.trim().is_empty() => {
+                parse_string_to_usize(&Some(input.trim().to_string()), &preferences.locales)?
+            }
+            _ => 1,
+        };
+        sections = number_headings(sections, start_level.max(1));
+    }
+    if options.format_options.generate_table_of_contents == Some(true) {
+        let link_style = options
+            .format_options
+            .table_of_contents_link_style
+            .unwrap_or(TableOfContentsLinkStyle::Wikilink);
+        let depth = match &options.format_options.table_of_contents_depth {
+            Some(input) if !input.trim().is_empty() => Some(parse_string_to_usize(
+                &Some(input.trim().to_string()),
+                &preferences.locales,
+            )?),
+            _ => None,
+        };
+        sections = generate_table_of_contents(sections, link_style, depth);
+    }
+
+    if options.format_options.renumber_footnotes == Some(true) {
+        sections = renumber_footnotes(sections);
+    }
+    if options.format_options.move_footnotes_to_end == Some(true) {
+        sections = move_footnotes_to_end(sections);
+    }
+
+    Ok(sections)
+}
@@ -0,0 +1,204 @@
+use std::error::Error;
+
+use crate::option_schema::CalloutTypeCase;
+use crate::tools::formatting::get_formatted_string;
+use crate::tools::parsing::{callouts::get_callout_header, get_sections};
+use crate::tools::tokens::{CalloutFold, CalloutHeader, MarkdownSection};
+use crate::Preferences;
+
+/// Normalizes the types of callouts with aliases and a case.
+pub fn normalize_callout_types(
+    sections: Vec<MarkdownSection>,
+    case: CalloutTypeCase,
+    aliases: &str,
+) -> Vec<MarkdownSection> {
+    let aliases = get_callout_type_aliases(aliases);
+
+    sections
+        .into_iter()
+        .map(|section| match section {
+            MarkdownSection::Callout(content) => {
+                let (first_line, rest) = match content.find('\n') {
+                    Some(position) => content.split_at(position),
+                    None => (content.as_str(), ""),
+                };
+
+                match get_callout_header(first_line) {
+                    Some(mut header) => {
+                        if let Some((_, name)) = aliases
+                            .iter()
+                            .find(|(alias, _)| alias.eq_ignore_ascii_case(&header.callout_type))
+                        {
+                            header.callout_type = name.to_string();
+                        }
+                        header.callout_type = match case {
+                            CalloutTypeCase::Lowercase => header.callout_type.to_lowercase(),
+                            CalloutTypeCase::Uppercase => header.callout_type.to_uppercase(),
+                            CalloutTypeCase::Preserve => header.callout_type,
+                        };
+
+                        MarkdownSection::Callout(format!("{}{}", get_header_line(&header), rest))
+                    }
+                    None => MarkdownSection::Callout(content),
+                }
+            }
+            _ => section,
+        })
+        .collect()
+}
+
+/// Inserts a space after the quote markers of every callout and blockquote line.
+/// (ex: `>text` -> `> text`)
+pub fn insert_spaces_after_callout_markers(sections: Vec<MarkdownSection>) -> Vec<MarkdownSection> {
+    sections
+        .into_iter()
+        .map(|section| match section {
+            MarkdownSection::Callout(content) => {
+                MarkdownSection::Callout(insert_spaces_after_markers(&content))
+            }
+            MarkdownSection::Blockquote(content) => {
+                MarkdownSection::Blockquote(insert_spaces_after_markers(&content))
+            }
+            _ => section,
+        })
+        .collect()
+}
+
+/// Formats the contents of callouts and blockquotes like a document,
+/// then puts quote markers back.
+/// Headers of callouts are left untouched.
+pub fn format_quote_interiors(
+    sections: Vec<MarkdownSection>,
+    preferences: &Preferences,
+) -> Result<Vec<MarkdownSection>, Box<dyn Error>> {
+    sections
+        .into_iter()
+        .map(|section| match section {
+            MarkdownSection::Callout(content) => {
+                let (header, body) = match content.split_once('\n') {
+                    Some((header, body)) => (header, body),
+                    None => (content.as_str(), ""),
+                };
+                if body.is_empty() {
+                    return Ok(MarkdownSection::Callout(content));
+                }
+
+                let body = get_formatted_quote_interior(body, preferences)?;
+                Ok(MarkdownSection::Callout(format!("{}\n{}", header, body)))
+            }
+            MarkdownSection::Blockquote(content) => Ok(MarkdownSection::Blockquote(
+                get_formatted_quote_interior(&content, preferences)?,
+            )),
+            _ => Ok(section),
+        })
+        .collect()
+}
+
+/// Strips quote markers, formats the text and puts the markers back.
+fn get_formatted_quote_interior(
+    content: &str,
+    preferences: &Preferences,
+) -> Result<String, Box<dyn Error>> {
+    let interior = content
+        .split('\n')
+        .map(remove_quote_marker)
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    // Interiors that cannot be parsed, like unclosed code blocks, are kept as they are.
+    // Line numbers in parsing errors would not match the document anyway.
+    let sections = match get_sections(&interior, preferences) {
+        Ok(sections) => sections,
+        Err(_) => return Ok(content.to_string()),
+    };
+    // Quotes nested inside are formatted as well.
+    let sections = format_quote_interiors(sections, preferences)?;
+    let formatted_interior = get_formatted_string(sections, preferences)?;
+
+    Ok(formatted_interior
+        .trim_end_matches('\n')
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                String::from(">")
+            } else {
+                format!("> {}", line)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+/// Removes a quote marker and a space after it.
+/// Lazy continuation lines without markers are left untouched.
+fn remove_quote_marker(line: &str) -> &str {
+    let trimmed_line = line.trim_start();
+    if line.len() - trimmed_line.len() > 3 {
+        return line;
+    }
+
+    match trimmed_line.strip_prefix('>') {
+        Some(text) => text.strip_prefix(' ').unwrap_or(text),
+        None => line,
+    }
+}
+
+/// Reads aliases from a comma separated list. (ex: `warn=warning, info=note`)
+fn get_callout_type_aliases(input: &str) -> Vec<(&str, &str)> {
+    input
+        .split(',')
+        .filter_map(|item| {
+            let (alias, name) = item.split_once('=')?;
+            let (alias, name) = (alias.trim(), name.trim());
+
+            if alias.is_empty() || name.is_empty() {
+                None
+            } else {
+                Some((alias, name))
+            }
+        })
+        .collect()
+}
+
+/// Writes a callout header back to a line.
+fn get_header_line(header: &CalloutHeader) -> String {
+    let fold = match header.fold {
+        Some(CalloutFold::Expanded) => "+",
+        Some(CalloutFold::Collapsed) => "-",
+        None => "",
+    };
+    let title = if header.title.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", header.title)
+    };
+
+    format!(
+        "{}[!{}]{}{}",
+        header.prefix, header.callout_type, fold, title
+    )
+}
+
+/// Inserts a space after the quote markers of every line in a text.
+fn insert_spaces_after_markers(content: &str) -> String {
+    content
+        .split('\n')
+        .map(insert_space_after_markers)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Inserts a space after the quote markers of a line.
+/// Lazy continuation lines without markers are left untouched.
+fn insert_space_after_markers(line: &str) -> String {
+    let markers_end = line
+        .find(|c: char| c != '>' && c != ' ')
+        .unwrap_or(line.len());
+    let (markers, text) = line.split_at(markers_end);
+
+    if markers.trim_start().starts_with('>') && !text.is_empty() && !markers.ends_with(' ') {
+        format!("{} {}", markers, text)
+    } else {
+        line.to_string()
+    }
+}
@@ -0,0 +1,192 @@
+use crate::tools::parsing::footnotes::get_footnote_definition_label;
+use crate::tools::tokens::{HeadingLevel, MarkdownSection};
+
+/// Moves every footnote definition to the end of a document.
+/// Footnotes are sorted by the order of their first references.
+/// Footnotes without references keep their order and go after the others.
+pub fn move_footnotes_to_end(sections: Vec<MarkdownSection>) -> Vec<MarkdownSection> {
+    let (mut footnotes, mut other_sections): (Vec<MarkdownSection>, Vec<MarkdownSection>) =
+        sections
+            .into_iter()
+            .partition(|section| matches!(section, MarkdownSection::Footnote(_)));
+
+    let mut references: Vec<String> = Vec::new();
+    for section in other_sections.iter() {
+        if let Some(text) = get_referencing_text(section) {
+            for label in get_footnote_references(text) {
+                if !references.contains(&label) {
+                    references.push(label);
+                }
+            }
+        }
+    }
+
+    // Stable sort keeps the order of footnotes without references.
+    footnotes.sort_by_key(|footnote| {
+        let label = match footnote {
+            MarkdownSection::Footnote(content) => get_footnote_definition_label(content),
+            _ => None,
+        };
+
+        label
+            .and_then(|label| references.iter().position(|item| item == label))
+            .unwrap_or(usize::MAX)
+    });
+
+    other_sections.append(&mut footnotes);
+    other_sections
+}
+
+/// Renumbers numeric footnotes by the order of their first references.
+/// Numeric footnotes without references get numbers after the others.
+/// Named footnotes are left untouched.
+pub fn renumber_footnotes(sections: Vec<MarkdownSection>) -> Vec<MarkdownSection> {
+    let is_numeric = |label: &str| label.chars().all(|c| c.is_ascii_digit());
+
+    // Numeric labels in the new order.
+    let mut numeric_labels: Vec<String> = Vec::new();
+    for section in sections.iter() {
+        if let Some(text) = get_referencing_text(section) {
+            for label in get_footnote_references(text) {
+                if is_numeric(&label) && !numeric_labels.contains(&label) {
+                    numeric_labels.push(label);
+                }
+            }
+        }
+    }
+    for section in sections.iter() {
+        if let MarkdownSection::Footnote(content) = section {
+            if let Some(label) = get_footnote_definition_label(content) {
+                if is_numeric(label) && !numeric_labels.iter().any(|item| item == label) {
+                    numeric_labels.push(label.to_string());
+                }
+            }
+        }
+    }
+
+    let get_new_label = |label: &str| -> Option<String> {
+        numeric_labels
+            .iter()
+            .position(|item| item == label)
+            .map(|position| (position + 1).to_string())
+    };
+
+    sections
+        .into_iter()
+        .map(|section| match section {
+            MarkdownSection::Heading(HeadingLevel::Top(content)) => MarkdownSection::Heading(
+                HeadingLevel::Top(replace_footnote_labels(&content, &get_new_label)),
+            ),
+            MarkdownSection::Heading(HeadingLevel::FirstSub(content)) => MarkdownSection::Heading(
+                HeadingLevel::FirstSub(replace_footnote_labels(&content, &get_new_label)),
+            ),
+            MarkdownSection::Heading(HeadingLevel::Sub(content)) => MarkdownSection::Heading(
+                HeadingLevel::Sub(replace_footnote_labels(&content, &get_new_label)),
+            ),
+            MarkdownSection::Content(content) => {
+                MarkdownSection::Content(replace_footnote_labels(&content, &get_new_label))
+            }
+            MarkdownSection::Callout(content) => {
+                MarkdownSection::Callout(replace_footnote_labels(&content, &get_new_label))
+            }
+            MarkdownSection::Blockquote(content) => {
+                MarkdownSection::Blockquote(replace_footnote_labels(&content, &get_new_label))
+            }
+            MarkdownSection::Footnote(content) => {
+                MarkdownSection::Footnote(replace_footnote_labels(&content, &get_new_label))
+            }
+            _ => section,
+        })
+        .collect()
+}
+
+/// Replaces labels of footnote references and footnote definitions in a text.
+fn replace_footnote_labels(text: &str, get_new_label: &dyn Fn(&str) -> Option<String>) -> String {
+    text.split('\n')
+        .map(|line| {
+            let mut replaced_line = String::new();
+            let mut copied_position = 0;
+
+            for (position, label) in find_footnote_references(line) {
+                if let Some(new_label) = get_new_label(label) {
+                    let label_position = position + "[^".len();
+                    replaced_line.push_str(&line[copied_position..label_position]);
+                    replaced_line.push_str(&new_label);
+                    copied_position = label_position + label.len();
+                }
+            }
+            replaced_line.push_str(&line[copied_position..]);
+
+            replaced_line
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Returns the text of a section that can have footnote references.
+/// Code blocks, math blocks, HTML blocks and comments don't have any.
+fn get_referencing_text(section: &MarkdownSection) -> Option<&str> {
+    match section {
+        MarkdownSection::Heading(
+            HeadingLevel::Top(content)
+            | HeadingLevel::FirstSub(content)
+            | HeadingLevel::Sub(content),
+        )
+        | MarkdownSection::Content(content)
+        | MarkdownSection::Callout(content)
+        | MarkdownSection::Blockquote(content)
+        | MarkdownSection::Footnote(content) => Some(content),
+        _ => None,
+    }
+}
+
+/// Returns labels of footnote references in a text, in order.
+/// References in inline code and labels of footnote definitions are ignored.
+fn get_footnote_references(text: &str) -> Vec<String> {
+    let mut references = Vec::new();
+
+    for line in text.split('\n') {
+        let definition_label_length =
+            get_footnote_definition_label(line).map_or(0, |label| label.len() + "[^]:".len());
+
+        for (position, label) in find_footnote_references(line) {
+            if position >= definition_label_length {
+                references.push(label.to_string());
+            }
+        }
+    }
+
+    references
+}
+
+/// Finds footnote references outside inline code, with their byte positions.
+fn find_footnote_references(line: &str) -> Vec<(usize, &str)> {
+    let mut references = Vec::new();
+    let mut is_inside_inline_code = false;
+    let mut position = 0;
+
+    while position < line.len() {
+        let rest = &line[position..];
+
+        if rest.starts_with('`') {
+            is_inside_inline_code = !is_inside_inline_code;
+            position += 1;
+            continue;
+        }
+
+        if !is_inside_inline_code && rest.starts_with("[^") {
+            if let Some(closing_position) = rest.find(']') {
+                let label = &rest[2..closing_position];
+                if !label.is_empty() && !label.contains(|c: char| c.is_whitespace() || c == '[') {
+                    references.push((position, label));
+                    position += closing_position + 1;
+                    continue;
+                }
+            }
+        }
+
+        position += rest.chars().next().map_or(1, |c| c.len_utf8());
+    }
+
+    references
+}
@@ -0,0 +1,361 @@
+use crate::option_schema::{HeadingCase, HeadingStyle};
+use crate::tools::parsing::get_sections;
+use crate::tools::parsing::headings::hash_headings::normalization::remove_closing_hashes;
+use crate::tools::tokens::{HeadingLevel, MarkdownSection};
+use crate::Preferences;
+
+mod case;
+
+pub use self::case::{get_small_words, DEFAULT_SMALL_WORDS};
+
+/// Punctuation characters removed from the end of heading titles by default.
+pub const DEFAULT_HEADING_TRAILING_PUNCTUATION: &str = ".:;!";
+
+/// A heading split into its level and title.
+pub struct Heading {
+    pub level: usize,
+    pub title: String,
+    /// The underline of an alternate heading. (ex: `===`)
+    pub underline: Option<String>,
+}
+
+/// Rewrites headings with the same style.
+/// Only level 1 and 2 headings can be alternate headings, so others stay as hash headings.
+pub fn convert_heading_styles(
+    sections: Vec<MarkdownSection>,
+    style: HeadingStyle,
+    preferences: &Preferences,
+) -> Vec<MarkdownSection> {
+    map_headings(sections, |mut heading| match style {
+        HeadingStyle::Atx if heading.underline.is_some() => {
+            heading.underline = None;
+            Some(heading)
+        }
+        HeadingStyle::Setext if heading.underline.is_none() && heading.level <= 2 => {
+            let symbol = if heading.level == 1 { "=" } else { "-" };
+            let underline = symbol.repeat(heading.title.chars().count().max(3));
+            let text = format!("{}\n{}", heading.title, underline);
+
+            // Titles that are read as another syntax stay as hash headings. (ex: `> Title`)
+            let is_alternate_heading = matches!(
+                get_sections(&text, preferences).as_deref(),
+                Ok([MarkdownSection::Heading(_)])
+            );
+            if !is_alternate_heading {
+                return None;
+            }
+
+            heading.underline = Some(underline);
+            Some(heading)
+        }
+        _ => None,
+    })
+}
+
+/// Removes trailing punctuation characters from heading titles. (ex: `Title:` -> `Title`)
+/// A semicolon ending an HTML entity is kept. (ex: `&amp;`)
+/// Titles with only a heading number are kept too. (ex: `1.2.`)
+pub fn remove_heading_trailing_punctuation(
+    sections: Vec<MarkdownSection>,
+    punctuation: &str,
+) -> Vec<MarkdownSection> {
+    map_headings(sections, |mut heading| {
+        if remove_heading_number(&heading.title).is_empty() {
+            return None;
+        }
+
+        let mut title = heading.title.as_str();
+        while let Some(last_char) = title.chars().last() {
+            if !punctuation.contains(last_char) || ends_with_html_entity(title) {
+                break;
+            }
+            title = title[..title.len() - last_char.len_utf8()].trim_end();
+        }
+
+        if title.is_empty() || title == heading.title {
+            return None;
+        }
+
+        heading.title = title.to_string();
+        Some(heading)
+    })
+}
+
+/// Changes the case of heading titles.
+/// In title case, small words stay lowercase unless they are the first or the last word.
+pub fn change_heading_case(
+    sections: Vec<MarkdownSection>,
+    case: HeadingCase,
+    small_words: &[String],
+) -> Vec<MarkdownSection> {
+    use self::case::get_title_with_case;
+
+    map_headings(sections, |mut heading| {
+        let title = get_title_with_case(&heading.title, case, small_words);
+        if title == heading.title {
+            return None;
+        }
+
+        heading.title = title;
+        Some(heading)
+    })
+}
+
+/// Prefixes headings with hierarchical numbers. (ex: `1.`, `1.1.`, `1.1.1.`)
+/// Headings above `start_level` are not numbered, and restart the numbers below them.
+/// Existing numbers are replaced, so numbering again does not change headings.
+/// The depth of a number is the count of numbered parent headings, so skipped levels
+/// don't add numbers. (ex: `##` -> `####` is numbered `1.` -> `1.1.`)
+pub fn number_headings(sections: Vec<MarkdownSection>, start_level: usize) -> Vec<MarkdownSection> {
+    let mut counters = [0; 6];
+    // Levels of numbered parent headings.
+    let mut parent_levels: Vec<usize> = Vec::new();
+
+    map_headings(sections, |mut heading| {
+        if heading.level < start_level {
+            counters = [0; 6];
+            parent_levels.clear();
+            return None;
+        }
+
+        while parent_levels
+            .last()
+            .is_some_and(|&parent_level| parent_level >= heading.level)
+        {
+            parent_levels.pop();
+        }
+        let depth = parent_levels.len().min(counters.len() - 1);
+        parent_levels.push(heading.level);
+
+        counters[depth] += 1;
+        for counter in &mut counters[depth + 1..] {
+            *counter = 0;
+        }
+
+        let number: String = counters[..=depth]
+            .iter()
+            .map(|counter| format!("{}.", counter))
+            .collect();
+        let title = remove_heading_number(&heading.title);
+        heading.title = if title.is_empty() {
+            number
+        } else {
+            format!("{} {}", number, title)
+        };
+
+        Some(heading)
+    })
+}
+
+/// Changes heading levels of a document, then decides which headings are top level ones again.
+/// - `demote_extra_h1_headings`: Demotes level 1 headings after the first one,
+///   with every heading after them.
+/// - `fix_skipped_levels`: Makes each heading at most one level deeper than its parent.
+///   (ex: `##` -> `####` becomes `##` -> `###`)
+/// - `top_level`: Shifts every heading so the top level heading has this level.
+pub fn repair_heading_levels(
+    sections: Vec<MarkdownSection>,
+    demote_extra_h1_headings: bool,
+    fix_skipped_levels: bool,
+    top_level: Option<usize>,
+) -> Vec<MarkdownSection> {
+    let mut levels: Vec<usize> = sections
+        .iter()
+        .filter_map(|section| match section {
+            MarkdownSection::Heading(heading) => Some(get_heading(get_text(heading)).level),
+            _ => None,
+        })
+        .collect();
+
+    if demote_extra_h1_headings {
+        let second_h1_position = levels
+            .iter()
+            .enumerate()
+            .filter(|(_, &level)| level == 1)
+            .nth(1)
+            .map(|(position, _)| position);
+        if let Some(position) = second_h1_position {
+            for level in &mut levels[position..] {
+                *level = (*level + 1).min(6);
+            }
+        }
+    }
+
+    if fix_skipped_levels {
+        levels = get_levels_without_skips(&levels);
+    }
+
+    if let (Some(top_level), Some(document_top_level)) = (top_level, levels.iter().copied().min()) {
+        for level in &mut levels {
+            *level = (*level + top_level)
+                .saturating_sub(document_top_level)
+                .clamp(1, 6);
+        }
+    }
+
+    let document_top_level = levels.iter().copied().min().unwrap_or_default();
+    let mut levels = levels.into_iter();
+    let mut current_level = 0;
+
+    sections
+        .into_iter()
+        .map(|section| match section {
+            MarkdownSection::Heading(heading) => {
+                let level = levels.next().unwrap_or_default();
+                let mut text = get_text(&heading).to_string();
+
+                let mut parts = get_heading(&text);
+                if parts.level != level {
+                    parts.level = level;
+                    parts.underline = match level {
+                        1 | 2 if parts.underline.is_some() => {
+                            let symbol = if level == 1 { "=" } else { "-" };
+                            Some(symbol.repeat(parts.title.chars().count().max(3)))
+                        }
+                        _ => None,
+                    };
+                    text = get_heading_text(&parts);
+                }
+
+                // Same as the parser.
+                let heading = if level == document_top_level {
+                    HeadingLevel::Top(text)
+                } else if level > current_level {
+                    HeadingLevel::FirstSub(text)
+                } else {
+                    HeadingLevel::Sub(text)
+                };
+                current_level = level;
+
+                MarkdownSection::Heading(heading)
+            }
+            _ => section,
+        })
+        .collect()
+}
+
+/// Returns levels where each heading is at most one level deeper than its parent.
+/// Headings without a parent keep their levels.
+pub fn get_levels_without_skips(levels: &[usize]) -> Vec<usize> {
+    // Original and new levels of parent headings.
+    let mut parents: Vec<(usize, usize)> = Vec::new();
+
+    levels
+        .iter()
+        .map(|&level| {
+            while parents
+                .last()
+                .is_some_and(|&(parent_level, _)| parent_level >= level)
+            {
+                parents.pop();
+            }
+
+            let new_level = match parents.last() {
+                Some(&(_, parent_new_level)) => parent_new_level + 1,
+                None => level,
+            };
+            parents.push((level, new_level));
+
+            new_level
+        })
+        .collect()
+}
+
+/// Applies a function to every heading, keeping its level in the document.
+/// Headings are left untouched when the function returns `None`.
+fn map_headings(
+    sections: Vec<MarkdownSection>,
+    mut f: impl FnMut(Heading) -> Option<Heading>,
+) -> Vec<MarkdownSection> {
+    let mut map_text = |text: String| match f(get_heading(&text)) {
+        Some(heading) => get_heading_text(&heading),
+        None => text,
+    };
+
+    sections
+        .into_iter()
+        .map(|section| match section {
+            MarkdownSection::Heading(HeadingLevel::Top(text)) => {
+                MarkdownSection::Heading(HeadingLevel::Top(map_text(text)))
+            }
+            MarkdownSection::Heading(HeadingLevel::FirstSub(text)) => {
+                MarkdownSection::Heading(HeadingLevel::FirstSub(map_text(text)))
+            }
+            MarkdownSection::Heading(HeadingLevel::Sub(text)) => {
+                MarkdownSection::Heading(HeadingLevel::Sub(map_text(text)))
+            }
+            _ => section,
+        })
+        .collect()
+}
+
+/// Checks if a title ends with an HTML entity. (ex: `&amp;`, `&#169;`)
+fn ends_with_html_entity(title: &str) -> bool {
+    let without_semicolon = match title.strip_suffix(';') {
+        Some(text) => text,
+        None => return false,
+    };
+    let name_start = match without_semicolon.rfind('&') {
+        Some(position) => position + 1,
+        None => return false,
+    };
+    let name = &without_semicolon[name_start..];
+    let name = name.strip_prefix('#').unwrap_or(name);
+
+    !name.is_empty() && name.chars().all(|char| char.is_ascii_alphanumeric())
+}
+
+/// Removes a number before a title. (ex: `1.2. Title` -> `Title`)
+/// Numbers must end with a dot, so titles like `2024 Review` are kept.
+fn remove_heading_number(title: &str) -> &str {
+    let mut rest = title;
+    loop {
+        let digit_count = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digit_count == 0 || !rest[digit_count..].starts_with('.') {
+            return title;
+        }
+
+        rest = &rest[digit_count + 1..];
+        if rest.is_empty() {
+            return rest;
+        }
+        if rest.starts_with(' ') || rest.starts_with('\t') {
+            return rest.trim_start();
+        }
+    }
+}
+
+pub fn get_text(heading: &HeadingLevel) -> &str {
+    match heading {
+        HeadingLevel::Top(text) | HeadingLevel::FirstSub(text) | HeadingLevel::Sub(text) => text,
+    }
+}
+
+/// Splits a hash heading or an alternate heading.
+pub fn get_heading(text: &str) -> Heading {
+    match text.split_once('\n') {
+        Some((title, underline)) => Heading {
+            level: if underline.starts_with('=') { 1 } else { 2 },
+            title: title.trim().to_string(),
+            underline: Some(underline.to_string()),
+        },
+        None => {
+            let level = text.chars().take_while(|&c| c == '#').count();
+
+            Heading {
+                level,
+                title: remove_closing_hashes(text[level..].trim()).to_string(),
+                underline: None,
+            }
+        }
+    }
+}
+
+/// Writes a heading back, as an alternate heading if it has an underline.
+fn get_heading_text(heading: &Heading) -> String {
+    match &heading.underline {
+        Some(underline) => format!("{}\n{}", heading.title, underline),
+        None if heading.title.is_empty() => "#".repeat(heading.level),
+        None => format!("{} {}", "#".repeat(heading.level), heading.title),
+    }
+}
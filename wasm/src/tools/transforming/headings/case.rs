@@ -0,0 +1,159 @@
+use crate::option_schema::HeadingCase;
+
+/// Words that stay lowercase in title case, unless they are the first or the last word.
+pub const DEFAULT_SMALL_WORDS: &str =
+    "a, an, and, as, at, but, by, for, in, nor, of, on, or, per, the, to, via, vs";
+
+/// Changes the case of a title.
+/// Inline code, links, wikilinks and tags are left untouched.
+pub fn get_title_with_case(title: &str, case: HeadingCase, small_words: &[String]) -> String {
+    let parts = split_title(title);
+
+    // Words without letters don't count. (ex: `1.2.` of numbered headings)
+    let word_positions: Vec<usize> = parts
+        .iter()
+        .enumerate()
+        .filter(|(_, (part, _))| part.chars().any(char::is_alphabetic))
+        .map(|(position, _)| position)
+        .collect();
+    let first_word_position = word_positions.first().copied();
+    let last_word_position = word_positions.last().copied();
+
+    parts
+        .iter()
+        .enumerate()
+        .map(|(position, &(part, is_protected))| {
+            if is_protected || !word_positions.contains(&position) {
+                return part.to_string();
+            }
+
+            let is_first_word = Some(position) == first_word_position;
+            let is_last_word = Some(position) == last_word_position;
+            match case {
+                HeadingCase::Title
+                    if !is_first_word && !is_last_word && is_small_word(part, small_words) =>
+                {
+                    part.to_lowercase()
+                }
+                // Words with uppercase letters are kept. (ex: `API`, `iPhone`)
+                HeadingCase::Title if part.chars().any(char::is_uppercase) => part.to_string(),
+                HeadingCase::Title => capitalize(part),
+                HeadingCase::Sentence if is_first_word => capitalize(part),
+                HeadingCase::Sentence if is_capitalized_word(part) => part.to_lowercase(),
+                HeadingCase::Sentence | HeadingCase::Preserve => part.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Parses a comma separated list of small words.
+pub fn get_small_words(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|word| word.trim().to_lowercase())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Splits a title into words, whitespace and protected parts.
+fn split_title(title: &str) -> Vec<(&str, bool)> {
+    let mut parts = Vec::new();
+    let mut rest = title;
+
+    while let Some(first_char) = rest.chars().next() {
+        // Tags only start at the beginning of words.
+        if let Some(length) = get_protected_length(rest).or_else(|| get_tag_length(rest)) {
+            parts.push((&rest[..length], true));
+            rest = &rest[length..];
+            continue;
+        }
+
+        let is_whitespace = first_char.is_whitespace();
+        let length = rest
+            .char_indices()
+            .skip(1)
+            .find(|&(index, char)| {
+                char.is_whitespace() != is_whitespace
+                    || get_protected_length(&rest[index..]).is_some()
+            })
+            .map_or(rest.len(), |(index, _)| index);
+
+        parts.push((&rest[..length], false));
+        rest = &rest[length..];
+    }
+
+    parts
+}
+
+/// Returns the length of inline code, a link or a wikilink at the start of a text.
+fn get_protected_length(text: &str) -> Option<usize> {
+    if text.starts_with('`') {
+        let backtick_count = text.chars().take_while(|&c| c == '`').count();
+        let backticks = "`".repeat(backtick_count);
+
+        return text[backtick_count..]
+            .find(&backticks)
+            .map(|position| backtick_count + position + backtick_count);
+    }
+
+    if text.starts_with("[[") || text.starts_with("![[") {
+        return text.find("]]").map(|position| position + 2);
+    }
+
+    if text.starts_with('[') || text.starts_with("![") {
+        let destination_start = text.find("](")?;
+        return text[destination_start..]
+            .find(')')
+            .map(|position| destination_start + position + 1);
+    }
+
+    None
+}
+
+/// Returns the length of a tag at the start of a text. (ex: `#tag`, `#nested/tag`)
+/// Numbers are not tags. (ex: `#1`)
+fn get_tag_length(text: &str) -> Option<usize> {
+    let name = text.strip_prefix('#')?;
+    let length = name
+        .find(|char: char| !(char.is_alphanumeric() || matches!(char, '_' | '-' | '/')))
+        .unwrap_or(name.len());
+    if name[..length].chars().all(|char| char.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(length + 1)
+}
+
+fn is_small_word(word: &str, small_words: &[String]) -> bool {
+    let word = word
+        .trim_matches(|char: char| !char.is_alphanumeric())
+        .to_lowercase();
+    small_words.contains(&word)
+}
+
+/// Checks if only the first letter of a word is uppercase. (ex: `Lorem`, not `API` or `iPhone`)
+/// `I` is not changed in sentence case.
+fn is_capitalized_word(word: &str) -> bool {
+    let mut letters = word.chars().filter(|char| char.is_alphabetic());
+
+    match letters.next() {
+        Some(first_letter) if first_letter.is_uppercase() => {
+            let mut rest = letters.peekable();
+            rest.peek().is_some() && rest.all(|char| !char.is_uppercase())
+        }
+        _ => false,
+    }
+}
+
+/// Changes the first letter of a word to uppercase.
+fn capitalize(word: &str) -> String {
+    match word.char_indices().find(|(_, char)| char.is_alphabetic()) {
+        Some((index, first_letter)) => format!(
+            "{}{}{}",
+            &word[..index],
+            first_letter.to_uppercase(),
+            &word[index + first_letter.len_utf8()..]
+        ),
+        None => word.to_string(),
+    }
+}
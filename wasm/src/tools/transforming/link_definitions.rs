@@ -0,0 +1,104 @@
+use crate::option_schema::LinkDefinitionPosition;
+use crate::tools::parsing::link_definitions::get_link_definition_label;
+use crate::tools::tokens::MarkdownSection;
+
+/// Removes link reference definitions identical to a previous one.
+/// Labels are compared case-insensitively, as they are matched in Markdown.
+pub fn deduplicate_link_definitions(sections: Vec<MarkdownSection>) -> Vec<MarkdownSection> {
+    let mut definitions: Vec<String> = Vec::new();
+
+    sections
+        .into_iter()
+        .filter(|section| match section {
+            MarkdownSection::LinkDefinition(content) => {
+                let definition = get_normalized_link_definition(content);
+                if definitions.contains(&definition) {
+                    false
+                } else {
+                    definitions.push(definition);
+                    true
+                }
+            }
+            _ => true,
+        })
+        .collect()
+}
+
+/// Moves link reference definitions to the end of a document or of their heading sections.
+pub fn move_link_definitions(
+    sections: Vec<MarkdownSection>,
+    position: LinkDefinitionPosition,
+) -> Vec<MarkdownSection> {
+    let mut output: Vec<MarkdownSection> = Vec::new();
+    let mut link_definitions: Vec<MarkdownSection> = Vec::new();
+
+    for section in sections {
+        match section {
+            MarkdownSection::LinkDefinition(_) if position != LinkDefinitionPosition::Preserve => {
+                link_definitions.push(section)
+            }
+            MarkdownSection::Heading(_) if position == LinkDefinitionPosition::SectionEnd => {
+                output.append(&mut link_definitions);
+                output.push(section);
+            }
+            _ => output.push(section),
+        }
+    }
+    output.append(&mut link_definitions);
+
+    output
+}
+
+/// Sorts every group of consecutive link reference definitions by their labels.
+pub fn sort_link_definitions(sections: Vec<MarkdownSection>) -> Vec<MarkdownSection> {
+    let mut output: Vec<MarkdownSection> = Vec::new();
+    let mut link_definitions: Vec<MarkdownSection> = Vec::new();
+
+    let append_sorted = |output: &mut Vec<MarkdownSection>,
+                         link_definitions: &mut Vec<MarkdownSection>| {
+        link_definitions.sort_by_key(|section| match section {
+            MarkdownSection::LinkDefinition(content) => get_link_definition_label(content)
+                .map(normalize_label)
+                .unwrap_or_default(),
+            _ => String::new(),
+        });
+        output.append(link_definitions);
+    };
+
+    for section in sections {
+        if matches!(section, MarkdownSection::LinkDefinition(_)) {
+            link_definitions.push(section);
+        } else {
+            append_sorted(&mut output, &mut link_definitions);
+            output.push(section);
+        }
+    }
+    append_sorted(&mut output, &mut link_definitions);
+
+    output
+}
+
+/// Returns a link reference definition with a normalized label and spacing.
+fn get_normalized_link_definition(content: &str) -> String {
+    match get_link_definition_label(content) {
+        Some(label) => {
+            let destination = &content.trim_start()[label.len() + 3..];
+            let destination = destination
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ");
+
+            format!("[{}]: {}", normalize_label(label), destination)
+        }
+        None => content.trim().to_string(),
+    }
+}
+
+/// Collapses whitespace and folds the case of a label.
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
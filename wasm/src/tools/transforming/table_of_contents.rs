@@ -0,0 +1,190 @@
+use super::headings::{get_heading, get_levels_without_skips, get_text};
+use crate::option_schema::TableOfContentsLinkStyle;
+use crate::tools::tokens::MarkdownSection;
+
+const TABLE_OF_CONTENTS_START: &str = "<!-- toc -->";
+const TABLE_OF_CONTENTS_END: &str = "<!-- tocstop -->";
+
+/// Replaces everything between `<!-- toc -->` and `<!-- tocstop -->` with a list of headings.
+/// Nested items are indented with tabs.
+/// - `depth`: The number of heading levels from the top level heading. (All levels when `None`.)
+pub fn generate_table_of_contents(
+    sections: Vec<MarkdownSection>,
+    link_style: TableOfContentsLinkStyle,
+    depth: Option<usize>,
+) -> Vec<MarkdownSection> {
+    let start = match sections
+        .iter()
+        .position(|section| is_marker(section, TABLE_OF_CONTENTS_START))
+    {
+        Some(start) => start,
+        None => return sections,
+    };
+    let end = match sections[start..]
+        .iter()
+        .position(|section| is_marker(section, TABLE_OF_CONTENTS_END))
+    {
+        Some(position) => start + position,
+        None => return sections,
+    };
+
+    let headings: Vec<_> = sections
+        .iter()
+        .filter_map(|section| match section {
+            MarkdownSection::Heading(heading) => Some(get_heading(get_text(heading))),
+            _ => None,
+        })
+        .filter(|heading| !heading.title.is_empty())
+        .collect();
+    let levels: Vec<usize> = headings.iter().map(|heading| heading.level).collect();
+    let top_level = levels.iter().copied().min().unwrap_or_default();
+
+    let mut used_slugs: Vec<String> = Vec::new();
+    let mut lines: Vec<String> = Vec::new();
+    for (heading, level) in headings.iter().zip(get_levels_without_skips(&levels)) {
+        let title = get_visible_text(&heading.title);
+        let link = match link_style {
+            TableOfContentsLinkStyle::Wikilink => {
+                format!("[[#{}]]", get_wikilink_target(&title))
+            }
+            TableOfContentsLinkStyle::Markdown => {
+                let slug = get_unique_slug(&title, &mut used_slugs);
+                format!("[{}](#{})", escape_brackets(&title), slug)
+            }
+        };
+
+        let indentation = level.saturating_sub(top_level);
+        if depth.is_none_or(|depth| indentation < depth) {
+            lines.push(format!("{}- {}", "\t".repeat(indentation), link));
+        }
+    }
+
+    let mut sections = sections;
+    let table_of_contents = if lines.is_empty() {
+        Vec::new()
+    } else {
+        vec![MarkdownSection::Content(lines.join("\n"))]
+    };
+    sections.splice(start + 1..end, table_of_contents);
+
+    sections
+}
+
+fn is_marker(section: &MarkdownSection, marker: &str) -> bool {
+    matches!(section, MarkdownSection::Html(content) if content.trim() == marker)
+}
+
+/// Returns the text of a title as it is displayed,
+/// without link, wikilink and emphasis syntax. Inline code is kept.
+/// (ex: `**Lorem** [Ipsum](https://example.com)` -> `Lorem Ipsum`)
+fn get_visible_text(title: &str) -> String {
+    let mut result = String::with_capacity(title.len());
+    let mut rest = title;
+
+    while let Some(char) = rest.chars().next() {
+        if char == '`' {
+            let backtick_count = rest.chars().take_while(|&c| c == '`').count();
+            let backticks = &rest[..backtick_count];
+            let code_length = match rest[backtick_count..].find(backticks) {
+                Some(position) => position + backtick_count * 2,
+                None => backtick_count,
+            };
+            result.push_str(&rest[..code_length]);
+            rest = &rest[code_length..];
+            continue;
+        }
+
+        if let Some(inner) = rest.strip_prefix("[[") {
+            if let Some(end) = inner.find("]]") {
+                let target = &inner[..end];
+                let text = match target.split_once('|') {
+                    Some((_, alias)) => alias,
+                    None => target,
+                };
+                result.push_str(&get_visible_text(text));
+                rest = &inner[end + 2..];
+                continue;
+            }
+        }
+
+        let link = rest.strip_prefix("![").or_else(|| rest.strip_prefix('['));
+        if let Some(inner) = link {
+            if let Some((text, after_text)) = inner.split_once(']') {
+                let destination_end = after_text
+                    .strip_prefix('(')
+                    .and_then(|destination| destination.find(')'));
+                if let Some(destination_end) = destination_end {
+                    result.push_str(&get_visible_text(text));
+                    rest = &after_text[destination_end + 2..];
+                    continue;
+                }
+            }
+        }
+
+        let marker_length = if ["**", "__", "~~", "=="]
+            .iter()
+            .any(|marker| rest.starts_with(marker))
+        {
+            2
+        } else if char == '*' {
+            1
+        } else if char == '_' {
+            // Underscores inside words are not emphasis. (ex: `snake_case`)
+            let is_after_word = result.chars().last().is_some_and(char::is_alphanumeric);
+            let is_before_word = rest[1..].chars().next().is_some_and(char::is_alphanumeric);
+            if is_after_word && is_before_word {
+                0
+            } else {
+                1
+            }
+        } else {
+            0
+        };
+        if marker_length > 0 {
+            rest = &rest[marker_length..];
+            continue;
+        }
+
+        result.push(char);
+        rest = &rest[char.len_utf8()..];
+    }
+
+    result
+}
+
+/// Replaces characters that can't be used in heading links of Obsidian.
+fn get_wikilink_target(title: &str) -> String {
+    title
+        .replace(['#', '|', '^', ':', '[', ']'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns a GitHub style anchor, with a number when the same one is already used.
+/// (ex: `Lorem Ipsum!` -> `lorem-ipsum`, `lorem-ipsum-1`)
+fn get_unique_slug(title: &str, used_slugs: &mut Vec<String>) -> String {
+    let slug: String = title
+        .to_lowercase()
+        .chars()
+        .filter_map(|char| match char {
+            ' ' => Some('-'),
+            '-' | '_' => Some(char),
+            _ if char.is_alphanumeric() => Some(char),
+            _ => None,
+        })
+        .collect();
+
+    let count = used_slugs.iter().filter(|used| **used == slug).count();
+    used_slugs.push(slug.clone());
+
+    if count == 0 {
+        slug
+    } else {
+        format!("{}-{}", slug, count)
+    }
+}
+
+fn escape_brackets(title: &str) -> String {
+    title.replace('[', "\\[").replace(']', "\\]")
+}
@@ -2,7 +2,7 @@ use serde_json::Value;
 use std::error::Error;
 use wasm_bindgen::JsValue;
 
-use crate::option_schema::PluginOptions;
+use crate::option_schema::{LineEnding, PluginOptions};
 
 // To use the feature, use the following argument.
 // "--features development"
@@ -40,6 +40,25 @@ pub fn read_js_value(js_value: JsValue) -> Result<Value, Box<dyn Error>> {
     }
 }
 
+/// Returns the line ending of an output.
+/// `Auto` follows the line ending used the most in an input.
+pub fn get_line_ending(input: &str, option: Option<LineEnding>) -> &'static str {
+    match option {
+        Some(LineEnding::Lf) => "\n",
+        Some(LineEnding::Crlf) => "\r\n",
+        Some(LineEnding::Auto) | None => {
+            let crlf_count = input.matches("\r\n").count();
+            let lf_count = input.matches('\n').count() - crlf_count;
+
+            if crlf_count > lf_count {
+                "\r\n"
+            } else {
+                "\n"
+            }
+        }
+    }
+}
+
 /// Message categories.
 pub enum LocaleCategory {
     Parsing,